@@ -0,0 +1,219 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+use crate::utils::{calculate_gaussian_kernel_1d, luminance_plane, separable_convolution};
+use crate::Image;
+
+/// Weights of the five scales of MS-SSIM, finest first
+const MS_SSIM_WEIGHTS: [f64; 5] = [0.0448, 0.2856, 0.3001, 0.2363, 0.1333];
+
+/// Stabilizing constants of SSIM for the 0 - 255 range
+const C1: f32 = (0.01 * 255.0) * (0.01 * 255.0);
+const C2: f32 = (0.03 * 255.0) * (0.03 * 255.0);
+
+/// Per-pixel comparison of two images
+pub struct Difference {
+    /// Absolute difference of every channel, black where the pixels are within the tolerance
+    pub image: Image,
+    /// Number of pixels differing by more than the tolerance
+    pub count: usize,
+    /// Largest difference of a single channel
+    pub max: u8
+}
+
+fn assert_same_size(a: &dyn ImageFormat, b: &dyn ImageFormat) {
+    assert!(
+        a.get_width() == b.get_width() && a.get_height() == b.get_height(),
+        "Images must have the same dimensions"
+    );
+}
+
+fn pixel_at(img: &dyn ImageFormat, x: usize, y: usize) -> Pixel {
+    img.get_pixel(x, y).cloned().unwrap_or_default()
+}
+
+/// Mean squared error over all color channels
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::analysis::mse;
+///
+/// let a = Image::from_file("<path>").expect("File not found!");
+/// let b = Image::from_file("<path>").expect("File not found!");
+/// println!("{}", mse(&a, &b));
+/// ```
+pub fn mse(a: &dyn ImageFormat, b: &dyn ImageFormat) -> f64 {
+    assert_same_size(a, b);
+
+    let mut sum = 0u64;
+    for y in 0..a.get_height() {
+        for x in 0..a.get_width() {
+            sum += pixel_at(a, x, y).distance_squared(&pixel_at(b, x, y)) as u64;
+        }
+    }
+
+    let samples = (a.get_width() * a.get_height() * 3).max(1);
+    sum as f64 / samples as f64
+}
+
+/// Peak signal to noise ratio in decibels, infinite for identical images
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::analysis::psnr;
+/// use rusty_imager::filters::GaussianBlur;
+///
+/// let golden = Image::from_file("<path>").expect("File not found!");
+/// let mut output = Image::from_file("<path>").expect("File not found!");
+/// output.apply_filter(GaussianBlur::new(1.0, 3));
+///
+/// assert!(psnr(&output, &golden) >= 40.0);
+/// ```
+pub fn psnr(a: &dyn ImageFormat, b: &dyn ImageFormat) -> f64 {
+    let error = mse(a, b);
+    if error == 0.0 {
+        return f64::INFINITY;
+    }
+
+    10.0 * (255.0 * 255.0 / error).log10()
+}
+
+/// Structural similarity of the luminance with a Gaussian window (sigma 1.5), 1 for identical images
+pub fn ssim(a: &dyn ImageFormat, b: &dyn ImageFormat) -> f64 {
+    assert_same_size(a, b);
+
+    let (a, b, width, height) = planes(a, b);
+    let (luminance, contrast_structure) = ssim_components(&a, &b, width, height);
+
+    luminance.iter().zip(&contrast_structure).map(|(l, cs)| (l * cs) as f64).sum::<f64>() / luminance.len().max(1) as f64
+}
+
+/// Multi-scale structural similarity over up to five halvings of the images
+///
+/// Less sensitive to the viewing distance than `ssim`, scales smaller than the window are skipped
+pub fn ms_ssim(a: &dyn ImageFormat, b: &dyn ImageFormat) -> f64 {
+    assert_same_size(a, b);
+
+    let (mut a, mut b, mut width, mut height) = planes(a, b);
+
+    let mut scales = 1;
+    while scales < MS_SSIM_WEIGHTS.len() && (width >> scales) >= 11 && (height >> scales) >= 11 {
+        scales += 1;
+    }
+
+    let weight_sum: f64 = MS_SSIM_WEIGHTS[..scales].iter().sum();
+    let mut res = 1f64;
+
+    for (scale, weight) in MS_SSIM_WEIGHTS[..scales].iter().enumerate() {
+        let (luminance, contrast_structure) = ssim_components(&a, &b, width, height);
+        let n = luminance.len().max(1) as f64;
+        let weight = weight / weight_sum;
+
+        let cs = contrast_structure.iter().map(|&v| v as f64).sum::<f64>() / n;
+        res *= cs.max(0.0).powf(weight);
+
+        if scale == scales - 1 {
+            let l = luminance.iter().map(|&v| v as f64).sum::<f64>() / n;
+            res *= l.max(0.0).powf(weight);
+        } else {
+            a = downsample(&a, width, height);
+            b = downsample(&b, width, height);
+            width /= 2;
+            height /= 2;
+        }
+    }
+
+    res
+}
+
+/// Compares the images pixel by pixel
+/// * `tolerance` - largest channel difference still considered equal
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::analysis::difference;
+///
+/// let golden = Image::from_file("<path>").expect("File not found!");
+/// let output = Image::from_file("<path>").expect("File not found!");
+///
+/// let diff = difference(&output, &golden, 2);
+/// if diff.count > 0 {
+///     diff.image.save("<name>.bmp").expect("Failed to save the image");
+/// }
+/// ```
+pub fn difference(a: &dyn ImageFormat, b: &dyn ImageFormat, tolerance: u8) -> Difference {
+    assert_same_size(a, b);
+
+    let width = a.get_width();
+    let height = a.get_height();
+    let mut image = Image::new(width, height);
+    let mut count = 0;
+    let mut max = 0;
+
+    for y in 0..height {
+        for x in 0..width {
+            let p = pixel_at(a, x, y);
+            let q = pixel_at(b, x, y);
+            let diff = Pixel {
+                r: p.r.abs_diff(q.r),
+                g: p.g.abs_diff(q.g),
+                b: p.b.abs_diff(q.b)
+            };
+
+            let largest = diff.r.max(diff.g).max(diff.b);
+            max = max.max(largest);
+
+            if largest > tolerance {
+                count += 1;
+                image.set_pixel(x, y, diff);
+            }
+        }
+    }
+
+    Difference { image, count, max }
+}
+
+fn planes(a: &dyn ImageFormat, b: &dyn ImageFormat) -> (Vec<f32>, Vec<f32>, usize, usize) {
+    let to_f32 = |img: &dyn ImageFormat| luminance_plane(img).iter().map(|&v| v as f32).collect::<Vec<f32>>();
+
+    (to_f32(a), to_f32(b), a.get_width(), a.get_height())
+}
+
+/// Luminance and contrast-structure terms of SSIM for every pixel
+fn ssim_components(a: &[f32], b: &[f32], width: usize, height: usize) -> (Vec<f32>, Vec<f32>) {
+    let kernel = calculate_gaussian_kernel_1d(1.5, 11);
+    let blur = |plane: &[f32]| separable_convolution(plane, width, height, &kernel);
+
+    let mu_a = blur(a);
+    let mu_b = blur(b);
+    let aa = blur(&a.iter().map(|v| v * v).collect::<Vec<f32>>());
+    let bb = blur(&b.iter().map(|v| v * v).collect::<Vec<f32>>());
+    let ab = blur(&a.iter().zip(b).map(|(x, y)| x * y).collect::<Vec<f32>>());
+
+    let mut luminance = Vec::with_capacity(a.len());
+    let mut contrast_structure = Vec::with_capacity(a.len());
+
+    for i in 0..a.len() {
+        let var_a = (aa[i] - mu_a[i] * mu_a[i]).max(0.0);
+        let var_b = (bb[i] - mu_b[i] * mu_b[i]).max(0.0);
+        let covariance = ab[i] - mu_a[i] * mu_b[i];
+
+        luminance.push((2.0 * mu_a[i] * mu_b[i] + C1) / (mu_a[i] * mu_a[i] + mu_b[i] * mu_b[i] + C1));
+        contrast_structure.push((2.0 * covariance + C2) / (var_a + var_b + C2));
+    }
+
+    (luminance, contrast_structure)
+}
+
+/// Half sized plane, every value is the average of a 2x2 block
+fn downsample(plane: &[f32], width: usize, height: usize) -> Vec<f32> {
+    let mut res = Vec::with_capacity((width / 2) * (height / 2));
+
+    for y in 0..height / 2 {
+        for x in 0..width / 2 {
+            let at = |dx: usize, dy: usize| plane[(2 * y + dy) * width + 2 * x + dx];
+            res.push((at(0, 0) + at(1, 0) + at(0, 1) + at(1, 1)) / 4.0);
+        }
+    }
+
+    res
+}
\ No newline at end of file
@@ -0,0 +1,188 @@
+use crate::formats::ImageFormat;
+use crate::models::{Colors, Connectivity, Rect};
+use crate::utils::luminance_plane;
+use crate::Image;
+
+/// Statistics of a single connected component
+#[derive(Debug, Clone, PartialEq)]
+pub struct Component {
+    /// Label of the component in the label map, starting from 1
+    pub label: u32,
+    /// Number of pixels
+    pub area: usize,
+    pub bounds: Rect,
+    /// Average position of the pixels (x, y)
+    pub centroid: (f32, f32),
+    /// Number of pixel edges between the component and the background
+    pub perimeter: usize
+}
+
+/// Connected components of a binary image
+#[derive(Debug, Clone, PartialEq)]
+pub struct Components {
+    width: usize,
+    height: usize,
+    labels: Vec<u32>,
+    components: Vec<Component>
+}
+
+impl Components {
+    /// Labels the connected areas of bright pixels (luminance above 127), e.g. the output of `Threshold`
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::Components;
+    /// use rusty_imager::filters::Threshold;
+    /// use rusty_imager::models::Connectivity;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(Threshold::otsu());
+    /// 
+    /// let blobs = Components::from_image(&img, Connectivity::Eight).filter_by_area(20, usize::MAX);
+    /// println!("Found {} objects", blobs.components().len());
+    /// ```
+    pub fn from_image(img: &dyn ImageFormat, connectivity: Connectivity) -> Self {
+        let foreground: Vec<bool> = luminance_plane(img).iter().map(|&v| v > 127).collect();
+
+        Self::from_mask(&foreground, img.get_width(), img.get_height(), connectivity)
+    }
+
+    /// Labels the connected areas of `true` values stored row by row
+    pub fn from_mask(mask: &[bool], width: usize, height: usize, connectivity: Connectivity) -> Self {
+        assert_eq!(mask.len(), width * height, "Mask must match the dimensions");
+
+        let mut labels = vec![0u32; width * height];
+        let mut components: Vec<Component> = vec![];
+        let mut stack: Vec<(usize, usize)> = vec![];
+
+        for start in 0..width * height {
+            if !mask[start] || labels[start] != 0 {
+                continue;
+            }
+
+            let label = components.len() as u32 + 1;
+            let (mut min_x, mut min_y, mut max_x, mut max_y) = (usize::MAX, usize::MAX, 0, 0);
+            let (mut sum_x, mut sum_y) = (0f64, 0f64);
+            let mut area = 0;
+            let mut perimeter = 0;
+
+            labels[start] = label;
+            stack.push((start % width, start / width));
+
+            while let Some((x, y)) = stack.pop() {
+                area += 1;
+                sum_x += x as f64;
+                sum_y += y as f64;
+                min_x = min_x.min(x);
+                min_y = min_y.min(y);
+                max_x = max_x.max(x);
+                max_y = max_y.max(y);
+
+                for &(dx, dy) in Connectivity::Four.offsets() {
+                    let nx = x as isize + dx;
+                    let ny = y as isize + dy;
+
+                    if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize || !mask[ny as usize * width + nx as usize] {
+                        perimeter += 1;
+                    }
+                }
+
+                for &(dx, dy) in connectivity.offsets() {
+                    let nx = x as isize + dx;
+                    let ny = y as isize + dy;
+
+                    if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize {
+                        continue;
+                    }
+
+                    let idx = ny as usize * width + nx as usize;
+                    if mask[idx] && labels[idx] == 0 {
+                        labels[idx] = label;
+                        stack.push((nx as usize, ny as usize));
+                    }
+                }
+            }
+
+            components.push(Component {
+                label,
+                area,
+                bounds: Rect::new(min_x, min_y, max_x - min_x + 1, max_y - min_y + 1),
+                centroid: ((sum_x / area as f64) as f32, (sum_y / area as f64) as f32),
+                perimeter
+            });
+        }
+
+        Self {
+            width,
+            height,
+            labels,
+            components
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Label of every pixel stored row by row, 0 is the background
+    pub fn labels(&self) -> &[u32] {
+        &self.labels
+    }
+
+    /// Gets the label of the pixel at x and y, 0 is the background
+    pub fn get_label(&self, x: usize, y: usize) -> Option<u32> {
+        if x >= self.width || y >= self.height {
+            return None
+        }
+
+        self.labels.get(y * self.width + x).copied()
+    }
+
+    pub fn components(&self) -> &[Component] {
+        &self.components
+    }
+
+    /// Keeps only the components with area in `min_area..=max_area`, the rest becomes background
+    /// 
+    /// The kept components are labeled again from 1
+    pub fn filter_by_area(&self, min_area: usize, max_area: usize) -> Self {
+        let mut mapping = vec![0u32; self.components.len() + 1];
+        let mut components: Vec<Component> = vec![];
+
+        for component in &self.components {
+            if (min_area..=max_area).contains(&component.area) {
+                let label = components.len() as u32 + 1;
+                mapping[component.label as usize] = label;
+
+                components.push(Component {
+                    label,
+                    ..component.clone()
+                });
+            }
+        }
+
+        Self {
+            width: self.width,
+            height: self.height,
+            labels: self.labels.iter().map(|&label| mapping[label as usize]).collect(),
+            components
+        }
+    }
+
+    /// Renders the components white on black background
+    pub fn to_image(&self) -> Image {
+        let mut img = Image::new(self.width, self.height);
+
+        for (idx, &label) in self.labels.iter().enumerate() {
+            if label != 0 {
+                img.set_pixel(idx % self.width, idx / self.width, Colors::WHITE);
+            }
+        }
+
+        img
+    }
+}
\ No newline at end of file
@@ -0,0 +1,207 @@
+use crate::formats::ImageFormat;
+use crate::models::Point;
+use crate::utils::luminance_plane;
+
+use super::geometry::{approximate_polygon, convex_hull, min_area_rect, polygon_area, polygon_perimeter, RotatedRect};
+
+/// Neighbour offsets (row, column) in clockwise order starting from the right
+const NEIGHBOURS: [(isize, isize); 8] = [(0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1), (-1, 0), (-1, 1)];
+
+/// Border of a connected area of bright pixels
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contour {
+    /// Pixel positions along the border
+    pub points: Vec<Point>,
+    /// Whether the contour is the border of a hole inside a shape
+    pub hole: bool,
+    /// Index of the contour enclosing this one, `None` for the outermost contours
+    pub parent: Option<usize>
+}
+
+impl Contour {
+    /// Traces the borders of the bright areas (luminance above 127) with the Suzuki-Abe border following
+    /// 
+    /// Outer borders and hole borders form a hierarchy through `parent`
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::Contour;
+    /// use rusty_imager::filters::Threshold;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(Threshold::otsu());
+    /// 
+    /// for contour in Contour::find_all(&img).iter().filter(|c| !c.hole) {
+    ///     let polygon = contour.approximate(contour.perimeter() * 0.02);
+    ///     if polygon.len() == 4 {
+    ///         println!("Quadrilateral: {:?}", polygon);
+    ///     }
+    /// }
+    /// ```
+    pub fn find_all(img: &dyn ImageFormat) -> Vec<Contour> {
+        let mask: Vec<bool> = luminance_plane(img).iter().map(|&v| v > 127).collect();
+
+        Self::find_in_mask(&mask, img.get_width(), img.get_height())
+    }
+
+    /// Traces the borders of the `true` areas of a mask stored row by row
+    pub fn find_in_mask(mask: &[bool], width: usize, height: usize) -> Vec<Contour> {
+        // Labels with a background frame around the image
+        let w = width + 2;
+        let h = height + 2;
+        let mut f = vec![0i32; w * h];
+
+        for y in 0..height {
+            for x in 0..width {
+                f[(y + 1) * w + x + 1] = mask[y * width + x] as i32;
+            }
+        }
+
+        // Border number 1 is the frame, an imaginary hole without a parent
+        let mut borders: Vec<(bool, Option<usize>)> = vec![(true, None)];
+        let mut contours: Vec<Contour> = vec![];
+        let mut nbd = 1i32;
+
+        for i in 1..h - 1 {
+            let mut lnbd = 1i32;
+
+            for j in 1..w - 1 {
+                let value = f[i * w + j];
+
+                let start = if value == 1 && f[i * w + j - 1] == 0 {
+                    Some((false, (i, j - 1)))
+                } else if value >= 1 && f[i * w + j + 1] == 0 {
+                    if value > 1 {
+                        lnbd = value;
+                    }
+
+                    Some((true, (i, j + 1)))
+                } else {
+                    None
+                };
+
+                if let Some((hole, from)) = start {
+                    nbd += 1;
+
+                    let (previous_hole, previous_parent) = borders[lnbd as usize - 1];
+                    let parent = if hole == previous_hole {
+                        previous_parent
+                    } else {
+                        Some(lnbd as usize - 1)
+                    };
+
+                    borders.push((hole, parent));
+
+                    let points = follow_border(&mut f, w, (i, j), from, nbd);
+                    contours.push(Contour {
+                        points,
+                        hole,
+                        parent: parent.filter(|&p| p > 0).map(|p| p - 1)
+                    });
+                }
+
+                let value = f[i * w + j];
+                if value != 0 && value != 1 {
+                    lnbd = value.abs();
+                }
+            }
+        }
+
+        contours
+    }
+
+    /// Area enclosed by the contour
+    pub fn area(&self) -> f32 {
+        polygon_area(&self.points)
+    }
+
+    /// Length of the contour
+    pub fn perimeter(&self) -> f32 {
+        polygon_perimeter(&self.points, true)
+    }
+
+    /// Simplifies the contour with the Douglas-Peucker algorithm
+    /// * `epsilon` - maximal distance of the removed points, a few percent of the perimeter works well
+    pub fn approximate(&self, epsilon: f32) -> Vec<Point> {
+        approximate_polygon(&self.points, epsilon, true)
+    }
+
+    pub fn convex_hull(&self) -> Vec<Point> {
+        convex_hull(&self.points)
+    }
+
+    /// Smallest rotated rectangle containing the contour
+    pub fn min_area_rect(&self) -> Option<RotatedRect> {
+        min_area_rect(&self.points)
+    }
+}
+
+fn direction(from: (usize, usize), to: (usize, usize)) -> usize {
+    let offset = (to.0 as isize - from.0 as isize, to.1 as isize - from.1 as isize);
+
+    NEIGHBOURS.iter().position(|&n| n == offset).unwrap()
+}
+
+fn step(from: (usize, usize), direction: usize) -> (usize, usize) {
+    let (di, dj) = NEIGHBOURS[direction];
+
+    ((from.0 as isize + di) as usize, (from.1 as isize + dj) as usize)
+}
+
+fn follow_border(f: &mut [i32], w: usize, start: (usize, usize), from: (usize, usize), nbd: i32) -> Vec<Point> {
+    let to_point = |p: (usize, usize)| Point::new((p.1 - 1) as f32, (p.0 - 1) as f32);
+
+    // Clockwise search for the first bright neighbour
+    let first = direction(start, from);
+    let found = (0..8)
+        .map(|k| step(start, (first + k) % 8))
+        .find(|p| f[p.0 * w + p.1] != 0);
+
+    let Some(p1) = found else {
+        // Single isolated pixel
+        f[start.0 * w + start.1] = -nbd;
+        return vec![to_point(start)]
+    };
+
+    let mut points: Vec<Point> = vec![];
+    let mut p2 = p1;
+    let mut p3 = start;
+
+    loop {
+        points.push(to_point(p3));
+
+        // Counterclockwise search starting after the previous pixel
+        let back = direction(p3, p2);
+        let mut p4 = p3;
+        let mut right_examined_empty = false;
+
+        for k in 1..=8 {
+            let dir = (back + 8 - k) % 8;
+            let p = step(p3, dir);
+
+            if f[p.0 * w + p.1] != 0 {
+                p4 = p;
+                break;
+            }
+
+            if dir == 0 {
+                right_examined_empty = true;
+            }
+        }
+
+        if right_examined_empty {
+            f[p3.0 * w + p3.1] = -nbd;
+        } else if f[p3.0 * w + p3.1] == 1 {
+            f[p3.0 * w + p3.1] = nbd;
+        }
+
+        if p4 == start && p3 == p1 {
+            break;
+        }
+
+        p2 = p3;
+        p3 = p4;
+    }
+
+    points
+}
\ No newline at end of file
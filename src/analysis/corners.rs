@@ -0,0 +1,100 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+use crate::utils::{calculate_gaussian_kernel_1d, luminance_plane, separable_convolution};
+use crate::Image;
+
+use super::gradient::{Gradient, GradientOperator};
+use super::keypoint::{local_maxima, Keypoint};
+
+/// Cornerness of every pixel computed from the smoothed structure tensor
+pub struct CornerResponse {
+    width: usize,
+    height: usize,
+    values: Vec<f32>
+}
+
+impl CornerResponse {
+    /// Harris response `det(M) - k * trace(M)^2`
+    /// * `k` - sensitivity, usually 0.04 - 0.06
+    /// * `sigma` - standard deviation of the window over which the gradients are summed
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::CornerResponse;
+    ///
+    /// let img = Image::from_file("<path>").expect("File not found!");
+    /// let corners = CornerResponse::harris(&img, 0.04, 1.5).keypoints(0.01, 3);
+    /// ```
+    pub fn harris(img: &dyn ImageFormat, k: f32, sigma: f32) -> Self {
+        Self::from_tensor(img, sigma, |xx, yy, xy| xx * yy - xy * xy - k * (xx + yy).powi(2))
+    }
+
+    /// Shi-Tomasi response, the smaller eigenvalue of the structure tensor
+    /// * `sigma` - standard deviation of the window over which the gradients are summed
+    pub fn shi_tomasi(img: &dyn ImageFormat, sigma: f32) -> Self {
+        Self::from_tensor(img, sigma, |xx, yy, xy| {
+            (xx + yy) / 2.0 - (((xx - yy) / 2.0).powi(2) + xy * xy).sqrt()
+        })
+    }
+
+    fn from_tensor(img: &dyn ImageFormat, sigma: f32, response: impl Fn(f32, f32, f32) -> f32) -> Self {
+        assert!(sigma > 0.0, "Sigma must be positive");
+
+        let width = img.get_width();
+        let height = img.get_height();
+
+        // Normalized so the responses do not depend on the 0 - 255 range
+        let plane: Vec<f32> = luminance_plane(img).iter().map(|&v| v as f32 / 255.0).collect();
+        let gradient = Gradient::from_plane(&plane, width, height, GradientOperator::Sobel);
+
+        let xx: Vec<f32> = gradient.x().iter().map(|gx| gx * gx).collect();
+        let yy: Vec<f32> = gradient.y().iter().map(|gy| gy * gy).collect();
+        let xy: Vec<f32> = gradient.x().iter().zip(gradient.y()).map(|(gx, gy)| gx * gy).collect();
+
+        let size = 2 * (3.0 * sigma).ceil() as u32 + 1;
+        let kernel = calculate_gaussian_kernel_1d(sigma, size);
+        let xx = separable_convolution(&xx, width, height, &kernel);
+        let yy = separable_convolution(&yy, width, height, &kernel);
+        let xy = separable_convolution(&xy, width, height, &kernel);
+
+        let values = (0..width * height).map(|i| response(xx[i], yy[i], xy[i])).collect();
+
+        Self { width, height, values }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Response of every pixel stored row by row
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Local maxima of the response (non-maximum suppression), strongest first
+    /// * `threshold` - minimal response relative to the strongest one (0 - 1)
+    /// * `radius` - radius of the suppression window
+    pub fn keypoints(&self, threshold: f32, radius: usize) -> Vec<Keypoint> {
+        let max = self.values.iter().copied().fold(0f32, f32::max);
+
+        local_maxima(&self.values, self.width, self.height, radius, max * threshold)
+    }
+
+    /// Renders the positive responses scaled so the strongest corner is white
+    pub fn to_image(&self) -> Image {
+        let max = self.values.iter().copied().fold(0f32, f32::max);
+        let scale = if max > 0.0 { 255.0 / max } else { 0.0 };
+        let mut img = Image::new(self.width, self.height);
+
+        for (idx, value) in self.values.iter().enumerate() {
+            let gray = (value * scale).round().clamp(0.0, 255.0) as u8;
+            img.set_pixel(idx % self.width, idx / self.width, Pixel { r: gray, g: gray, b: gray });
+        }
+
+        img
+    }
+}
\ No newline at end of file
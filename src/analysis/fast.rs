@@ -0,0 +1,110 @@
+use crate::formats::ImageFormat;
+use crate::utils::luminance_plane;
+
+use super::keypoint::{local_maxima, Keypoint};
+
+/// Bresenham circle of radius 3 around the tested pixel
+const CIRCLE: [(isize, isize); 16] = [
+    (0, -3), (1, -3), (2, -2), (3, -1), (3, 0), (3, 1), (2, 2), (1, 3),
+    (0, 3), (-1, 3), (-2, 2), (-3, 1), (-3, 0), (-3, -1), (-2, -2), (-1, -3)
+];
+
+/// FAST (features from accelerated segment test) corner detector
+pub struct Fast {
+    threshold: u8,
+    arc_length: usize,
+    nonmax: bool
+}
+
+impl Fast {
+    /// Creates FAST-9 detector with non-maximum suppression
+    /// * `threshold` - minimal brightness difference between the center and the circle pixels
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::Fast;
+    ///
+    /// let img = Image::from_file("<path>").expect("File not found!");
+    /// let keypoints = Fast::new(20).detect(&img);
+    /// ```
+    pub fn new(threshold: u8) -> Self {
+        Self::with_arc_length(threshold, 9, true)
+    }
+
+    /// * `threshold` - minimal brightness difference between the center and the circle pixels
+    /// * `arc_length` - number of contiguous circle pixels (9 - 12) that have to be brighter or darker
+    /// * `nonmax` - whether only the local maxima of the score are kept
+    pub fn with_arc_length(threshold: u8, arc_length: usize, nonmax: bool) -> Self {
+        assert!((9..=12).contains(&arc_length), "Arc length must be between 9 and 12");
+
+        Self { threshold, arc_length, nonmax }
+    }
+
+    /// Finds the corners, strongest first
+    ///
+    /// The score is the sum of absolute differences between the center and the circle pixels beyond the threshold
+    pub fn detect(&self, img: &dyn ImageFormat) -> Vec<Keypoint> {
+        let width = img.get_width();
+        let height = img.get_height();
+        let plane = luminance_plane(img);
+        let mut scores = vec![0f32; width * height];
+
+        for y in 3..height.saturating_sub(3) {
+            for x in 3..width.saturating_sub(3) {
+                scores[y * width + x] = self.score(&plane, width, x, y);
+            }
+        }
+
+        if self.nonmax {
+            return local_maxima(&scores, width, height, 1, 0.0);
+        }
+
+        let mut keypoints: Vec<Keypoint> = scores.iter().enumerate()
+            .filter(|(_, &s)| s > 0.0)
+            .map(|(i, &s)| Keypoint::new((i % width) as f32, (i / width) as f32, s))
+            .collect();
+
+        keypoints.sort_by(|a, b| b.score.total_cmp(&a.score));
+        keypoints
+    }
+
+    /// Corner score of the pixel, zero when the segment test fails
+    fn score(&self, plane: &[u8], width: usize, x: usize, y: usize) -> f32 {
+        let center = plane[y * width + x] as i32;
+        let t = self.threshold as i32;
+
+        // +1 brighter, -1 darker, 0 similar
+        let mut states = [0i8; 16];
+        let mut diffs = [0i32; 16];
+        for (i, (dx, dy)) in CIRCLE.iter().enumerate() {
+            let value = plane[(y as isize + dy) as usize * width + (x as isize + dx) as usize] as i32;
+            diffs[i] = value - center;
+            states[i] = if value > center + t { 1 } else if value < center - t { -1 } else { 0 };
+        }
+
+        for state in [1i8, -1] {
+            // Longest run on the circle, walked twice to handle wrap-around
+            let mut run = 0;
+            let mut longest = 0;
+            for i in 0..32 {
+                if states[i % 16] == state {
+                    run += 1;
+                    longest = longest.max(run);
+                } else {
+                    run = 0;
+                }
+            }
+
+            if longest >= self.arc_length {
+                let sum: i32 = (0..16)
+                    .filter(|&i| states[i] == state)
+                    .map(|i| diffs[i].abs() - t)
+                    .sum();
+
+                return sum as f32;
+            }
+        }
+
+        0.0
+    }
+}
\ No newline at end of file
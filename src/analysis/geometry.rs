@@ -0,0 +1,205 @@
+use crate::models::Point;
+
+/// Rectangle that can be rotated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotatedRect {
+    pub center: Point,
+    pub width: f32,
+    pub height: f32,
+    /// Angle of the width side in degrees
+    pub angle: f32
+}
+
+impl RotatedRect {
+    /// Gets the corners in order around the rectangle
+    pub fn corners(&self) -> [Point; 4] {
+        let (sin, cos) = self.angle.to_radians().sin_cos();
+        let w = (cos * self.width / 2.0, sin * self.width / 2.0);
+        let h = (-sin * self.height / 2.0, cos * self.height / 2.0);
+        let c = self.center;
+
+        [
+            Point::new(c.x - w.0 - h.0, c.y - w.1 - h.1),
+            Point::new(c.x + w.0 - h.0, c.y + w.1 - h.1),
+            Point::new(c.x + w.0 + h.0, c.y + w.1 + h.1),
+            Point::new(c.x - w.0 + h.0, c.y - w.1 + h.1)
+        ]
+    }
+
+    pub fn area(&self) -> f32 {
+        self.width * self.height
+    }
+}
+
+/// Area of a closed polygon (shoelace formula)
+pub fn polygon_area(points: &[Point]) -> f32 {
+    let mut sum = 0f32;
+
+    for (i, a) in points.iter().enumerate() {
+        let b = &points[(i + 1) % points.len()];
+        sum += a.x * b.y - b.x * a.y;
+    }
+
+    sum.abs() / 2.0
+}
+
+/// Length of a polygon, `closed` adds the segment from the last point back to the first one
+pub fn polygon_perimeter(points: &[Point], closed: bool) -> f32 {
+    let mut length: f32 = points.windows(2).map(|w| w[0].distance(&w[1])).sum();
+
+    if closed && points.len() > 2 {
+        length += points[points.len() - 1].distance(&points[0]);
+    }
+
+    length
+}
+
+/// Simplifies a polygon with the Douglas-Peucker algorithm
+/// * `epsilon` - maximal distance of the removed points from the simplified polygon
+/// * `closed` - the polygon is a closed shape (e.g. a contour)
+pub fn approximate_polygon(points: &[Point], epsilon: f32, closed: bool) -> Vec<Point> {
+    if points.len() < 3 {
+        return points.to_vec()
+    }
+
+    if !closed {
+        let mut keep = vec![false; points.len()];
+        keep[0] = true;
+        keep[points.len() - 1] = true;
+        douglas_peucker(points, 0, points.len() - 1, epsilon, &mut keep);
+
+        return points.iter().zip(keep).filter(|(_, k)| *k).map(|(p, _)| *p).collect()
+    }
+
+    // Split the closed shape at the point farthest from the first one
+    let far = (1..points.len())
+        .max_by(|&a, &b| points[0].distance(&points[a]).total_cmp(&points[0].distance(&points[b])))
+        .unwrap();
+
+    let mut ring = points.to_vec();
+    ring.push(points[0]);
+
+    let mut keep = vec![false; ring.len()];
+    keep[0] = true;
+    keep[far] = true;
+    douglas_peucker(&ring, 0, far, epsilon, &mut keep);
+    douglas_peucker(&ring, far, ring.len() - 1, epsilon, &mut keep);
+
+    points.iter().zip(keep).filter(|(_, k)| *k).map(|(p, _)| *p).collect()
+}
+
+fn douglas_peucker(points: &[Point], start: usize, end: usize, epsilon: f32, keep: &mut [bool]) {
+    if end <= start + 1 {
+        return;
+    }
+
+    let (a, b) = (points[start], points[end]);
+    let length = a.distance(&b);
+
+    let distance = |p: &Point| {
+        if length == 0.0 {
+            p.distance(&a)
+        } else {
+            ((b.x - a.x) * (a.y - p.y) - (a.x - p.x) * (b.y - a.y)).abs() / length
+        }
+    };
+
+    let (far, max) = (start + 1..end)
+        .map(|i| (i, distance(&points[i])))
+        .max_by(|x, y| x.1.total_cmp(&y.1))
+        .unwrap();
+
+    if max > epsilon {
+        keep[far] = true;
+        douglas_peucker(points, start, far, epsilon, keep);
+        douglas_peucker(points, far, end, epsilon, keep);
+    }
+}
+
+/// Smallest convex polygon containing all the points (Andrew's monotone chain), in counterclockwise order
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted
+    }
+
+    let cross = |o: &Point, a: &Point, b: &Point| (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x);
+    let half = |points: &mut dyn Iterator<Item = &Point>| {
+        let mut res: Vec<Point> = vec![];
+
+        for p in points {
+            while res.len() >= 2 && cross(&res[res.len() - 2], &res[res.len() - 1], p) <= 0.0 {
+                res.pop();
+            }
+
+            res.push(*p);
+        }
+
+        // The last point is the first point of the other half
+        res.pop();
+        res
+    };
+
+    let mut hull = half(&mut sorted.iter());
+    hull.extend(half(&mut sorted.iter().rev()));
+
+    hull
+}
+
+/// Smallest rotated rectangle containing all the points (rotating calipers over the convex hull)
+pub fn min_area_rect(points: &[Point]) -> Option<RotatedRect> {
+    let hull = convex_hull(points);
+
+    if hull.is_empty() {
+        return None
+    }
+
+    if hull.len() == 1 {
+        return Some(RotatedRect { center: hull[0], width: 0.0, height: 0.0, angle: 0.0 })
+    }
+
+    let mut best: Option<RotatedRect> = None;
+
+    for i in 0..hull.len() {
+        let a = hull[i];
+        let b = hull[(i + 1) % hull.len()];
+        let length = a.distance(&b);
+
+        if length == 0.0 {
+            continue;
+        }
+
+        let (ux, uy) = ((b.x - a.x) / length, (b.y - a.y) / length);
+
+        let (mut min_u, mut max_u, mut min_v, mut max_v) = (f32::MAX, f32::MIN, f32::MAX, f32::MIN);
+        for p in &hull {
+            let u = (p.x - a.x) * ux + (p.y - a.y) * uy;
+            let v = -(p.x - a.x) * uy + (p.y - a.y) * ux;
+
+            min_u = min_u.min(u);
+            max_u = max_u.max(u);
+            min_v = min_v.min(v);
+            max_v = max_v.max(v);
+        }
+
+        let width = max_u - min_u;
+        let height = max_v - min_v;
+
+        if best.is_none_or(|rect| width * height < rect.area()) {
+            let cu = (min_u + max_u) / 2.0;
+            let cv = (min_v + max_v) / 2.0;
+
+            best = Some(RotatedRect {
+                center: Point::new(a.x + cu * ux - cv * uy, a.y + cu * uy + cv * ux),
+                width,
+                height,
+                angle: uy.atan2(ux).to_degrees()
+            });
+        }
+    }
+
+    best
+}
\ No newline at end of file
@@ -0,0 +1,175 @@
+use std::f32::consts::PI;
+
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+use crate::utils::luminance_plane;
+use crate::Image;
+
+/// Kernel used to approximate the derivatives
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientOperator {
+    Sobel,
+    /// More rotationally symmetric than Sobel
+    Scharr,
+    Prewitt,
+    /// 2x2 diagonal differences, the most sensitive to noise
+    Roberts
+}
+
+impl GradientOperator {
+    /// Gets the 3x3 kernel of the x derivative, the y kernel is its transposition
+    fn kernel(&self) -> [[f32; 3]; 3] {
+        match self {
+            GradientOperator::Sobel => [
+                [-1.0, 0.0, 1.0],
+                [-2.0, 0.0, 2.0],
+                [-1.0, 0.0, 1.0]
+            ],
+
+            GradientOperator::Scharr => [
+                [-3.0, 0.0, 3.0],
+                [-10.0, 0.0, 10.0],
+                [-3.0, 0.0, 3.0]
+            ],
+
+            GradientOperator::Prewitt | GradientOperator::Roberts => [
+                [-1.0, 0.0, 1.0],
+                [-1.0, 0.0, 1.0],
+                [-1.0, 0.0, 1.0]
+            ]
+        }
+    }
+}
+
+/// Horizontal and vertical derivatives of the image luminance
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    width: usize,
+    height: usize,
+    x: Vec<f32>,
+    y: Vec<f32>
+}
+
+impl Gradient {
+    /// Computes the gradient of the image luminance
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::{Gradient, GradientOperator};
+    /// 
+    /// let img = Image::from_file("<path>").expect("File not found!");
+    /// let gradient = Gradient::from_image(&img, GradientOperator::Sobel);
+    /// 
+    /// gradient.magnitude_image().save("<magnitude>.bmp").expect("Failed to save the image");
+    /// gradient.direction_image().save("<direction>.bmp").expect("Failed to save the image");
+    /// ```
+    pub fn from_image(img: &dyn ImageFormat, operator: GradientOperator) -> Self {
+        let plane: Vec<f32> = luminance_plane(img).iter().map(|&v| v as f32).collect();
+
+        Self::from_plane(&plane, img.get_width(), img.get_height(), operator)
+    }
+
+    /// Computes the gradient of values stored row by row, edge values are repeated outside of the plane
+    pub fn from_plane(plane: &[f32], width: usize, height: usize, operator: GradientOperator) -> Self {
+        let mut x = vec![0f32; width * height];
+        let mut y = vec![0f32; width * height];
+
+        let value = |i: isize, j: isize| {
+            let i = i.clamp(0, width as isize - 1) as usize;
+            let j = j.clamp(0, height as isize - 1) as usize;
+
+            plane[j * width + i]
+        };
+
+        let kernel = operator.kernel();
+
+        for j in 0..height as isize {
+            for i in 0..width as isize {
+                let idx = j as usize * width + i as usize;
+
+                if operator == GradientOperator::Roberts {
+                    let d1 = value(i + 1, j + 1) - value(i, j);
+                    let d2 = value(i, j + 1) - value(i + 1, j);
+
+                    x[idx] = (d1 - d2) / 2.0;
+                    y[idx] = (d1 + d2) / 2.0;
+                    continue;
+                }
+
+                let mut gx = 0f32;
+                let mut gy = 0f32;
+
+                for k in -1..=1 {
+                    for l in -1..=1 {
+                        let pixel = value(i + l, j + k);
+                        gx += pixel * kernel[(k + 1) as usize][(l + 1) as usize];
+                        gy += pixel * kernel[(l + 1) as usize][(k + 1) as usize];
+                    }
+                }
+
+                x[idx] = gx;
+                y[idx] = gy;
+            }
+        }
+
+        Self {
+            width,
+            height,
+            x,
+            y
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Horizontal derivatives stored row by row
+    pub fn x(&self) -> &[f32] {
+        &self.x
+    }
+
+    /// Vertical derivatives stored row by row
+    pub fn y(&self) -> &[f32] {
+        &self.y
+    }
+
+    /// Length of the gradient of every pixel
+    pub fn magnitude(&self) -> Vec<f32> {
+        self.x.iter().zip(&self.y).map(|(gx, gy)| gx.hypot(*gy)).collect()
+    }
+
+    /// Angle of the gradient of every pixel in radians (-π - π)
+    pub fn direction(&self) -> Vec<f32> {
+        self.x.iter().zip(&self.y).map(|(gx, gy)| gy.atan2(*gx)).collect()
+    }
+
+    /// Renders the magnitude scaled so the strongest edge is white
+    pub fn magnitude_image(&self) -> Image {
+        let magnitude = self.magnitude();
+        let max = magnitude.iter().copied().fold(0f32, f32::max);
+        let scale = if max > 0.0 { 255.0 / max } else { 0.0 };
+
+        self.render(magnitude.iter().map(|m| m * scale))
+    }
+
+    /// Renders the direction mapped from -π - π to 0 - 255
+    pub fn direction_image(&self) -> Image {
+        self.render(self.direction().iter().map(|d| (d + PI) / (2.0 * PI) * 255.0))
+    }
+
+    fn render(&self, values: impl Iterator<Item = f32>) -> Image {
+        let mut img = Image::new(self.width, self.height);
+
+        for (idx, value) in values.enumerate() {
+            let gray = value.round().clamp(0.0, 255.0) as u8;
+            img.set_pixel(idx % self.width, idx / self.width, Pixel { r: gray, g: gray, b: gray });
+        }
+
+        img
+    }
+}
\ No newline at end of file
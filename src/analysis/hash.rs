@@ -0,0 +1,161 @@
+use std::f32::consts::PI;
+
+use crate::formats::ImageFormat;
+use crate::utils::{luminance_plane, resample_plane};
+
+/// 64 bit perceptual fingerprint of an image, similar images have hashes with a small Hamming distance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageHash {
+    bits: u64
+}
+
+impl ImageHash {
+    pub fn new(bits: u64) -> Self {
+        Self { bits }
+    }
+
+    /// Average hash, every bit tells whether a cell of an 8x8 thumbnail is brighter than the mean
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::ImageHash;
+    ///
+    /// let a = Image::from_file("<path>").expect("File not found!");
+    /// let b = Image::from_file("<path>").expect("File not found!");
+    ///
+    /// if ImageHash::perceptual(&a).distance(&ImageHash::perceptual(&b)) <= 10 {
+    ///     println!("Near duplicates");
+    /// }
+    /// ```
+    pub fn average(img: &dyn ImageFormat) -> Self {
+        let cells = thumbnail(img, 8, 8);
+        let mean = cells.iter().sum::<f32>() / cells.len() as f32;
+
+        Self::from_flags(cells.iter().map(|&v| v > mean))
+    }
+
+    /// Difference hash, every bit tells whether a cell of a 9x8 thumbnail is brighter than its right neighbour
+    pub fn difference(img: &dyn ImageFormat) -> Self {
+        let cells = thumbnail(img, 9, 8);
+
+        Self::from_flags((0..64).map(|i| {
+            let (x, y) = (i % 8, i / 8);
+            cells[y * 9 + x] > cells[y * 9 + x + 1]
+        }))
+    }
+
+    /// Perceptual hash, compares the lowest 8x8 frequencies of the DCT of a 32x32 thumbnail with their median
+    ///
+    /// The most robust of the hashes against recompression and small edits
+    pub fn perceptual(img: &dyn ImageFormat) -> Self {
+        const SIZE: usize = 32;
+
+        let cells = thumbnail(img, SIZE, SIZE);
+        let cos: Vec<f32> = (0..8 * SIZE)
+            .map(|i| {
+                let (u, x) = (i / SIZE, i % SIZE);
+                ((2 * x + 1) as f32 * u as f32 * PI / (2 * SIZE) as f32).cos()
+            })
+            .collect();
+
+        // Separable DCT-II limited to the 8 lowest frequencies in both directions
+        let mut rows = vec![0f32; SIZE * 8];
+        for y in 0..SIZE {
+            for u in 0..8 {
+                rows[y * 8 + u] = (0..SIZE).map(|x| cells[y * SIZE + x] * cos[u * SIZE + x]).sum();
+            }
+        }
+
+        let mut coefficients = vec![0f32; 64];
+        for v in 0..8 {
+            for u in 0..8 {
+                coefficients[v * 8 + u] = (0..SIZE).map(|y| rows[y * 8 + u] * cos[v * SIZE + y]).sum();
+            }
+        }
+
+        // The DC term only holds the average brightness, so it is left out of the median
+        let median = median(&coefficients[1..]);
+
+        Self::from_flags(coefficients.iter().map(|&c| c > median))
+    }
+
+    /// Wavelet hash, compares the approximation band of a three level Haar decomposition of a 64x64 thumbnail with its median
+    pub fn wavelet(img: &dyn ImageFormat) -> Self {
+        let mut size = 64;
+        let mut band = thumbnail(img, size, size);
+
+        for _ in 0..3 {
+            band = haar_approximation(&band, size);
+            size /= 2;
+        }
+
+        let median = median(&band);
+
+        Self::from_flags(band.iter().map(|&v| v > median))
+    }
+
+    /// Number of differing bits (0 - 64)
+    pub fn distance(&self, other: &ImageHash) -> u32 {
+        (self.bits ^ other.bits).count_ones()
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Formats the hash as 16 hexadecimal digits
+    pub fn to_hex(&self) -> String {
+        format!("{:016x}", self.bits)
+    }
+
+    /// Parses a hash stored with `to_hex`
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        if hex.len() != 16 {
+            return None;
+        }
+
+        Some(Self::new(u64::from_str_radix(hex, 16).ok()?))
+    }
+
+    /// Packs 64 flags, the first one becomes the most significant bit
+    fn from_flags(flags: impl Iterator<Item = bool>) -> Self {
+        Self::new(flags.take(64).fold(0u64, |bits, flag| (bits << 1) | flag as u64))
+    }
+}
+
+/// Luminance of the image shrunk to the given size
+fn thumbnail(img: &dyn ImageFormat, width: usize, height: usize) -> Vec<f32> {
+    let plane: Vec<f32> = luminance_plane(img).iter().map(|&v| v as f32).collect();
+    if plane.is_empty() {
+        return vec![0f32; width * height];
+    }
+
+    resample_plane(&plane, img.get_width(), img.get_height(), width, height)
+}
+
+/// Low pass band of a single level 2D Haar transform of a square plane
+fn haar_approximation(plane: &[f32], size: usize) -> Vec<f32> {
+    let half = size / 2;
+    let mut res = vec![0f32; half * half];
+
+    for y in 0..half {
+        for x in 0..half {
+            let at = |dx: usize, dy: usize| plane[(2 * y + dy) * size + 2 * x + dx];
+            res[y * half + x] = (at(0, 0) + at(1, 0) + at(0, 1) + at(1, 1)) / 2.0;
+        }
+    }
+
+    res
+}
+
+fn median(values: &[f32]) -> f32 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f32::total_cmp);
+
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
\ No newline at end of file
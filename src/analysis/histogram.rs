@@ -0,0 +1,197 @@
+use crate::formats::ImageFormat;
+use crate::models::{Channel, Rect};
+
+use super::Statistics;
+
+/// Per-channel and luminance histograms of an image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    red: [u32; 256],
+    green: [u32; 256],
+    blue: [u32; 256],
+    luminance: [u32; 256],
+    count: u32
+}
+
+impl Histogram {
+    /// Computes the histograms over the whole image
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::Histogram;
+    /// use rusty_imager::models::Channel;
+    /// 
+    /// let img = Image::from_file("<path>").expect("File not found!");
+    /// let hist = Histogram::from_image(&img);
+    /// let dark_pixels: u32 = hist.get(Channel::Luminance)[..64].iter().sum();
+    /// ```
+    pub fn from_image(img: &dyn ImageFormat) -> Self {
+        let region = Rect::new(0, 0, img.get_width(), img.get_height());
+
+        Self::from_region(img, region)
+    }
+
+    /// Computes the histograms over the part of the image covered by `region`
+    /// 
+    /// The region is clipped to the image bounds
+    pub fn from_region(img: &dyn ImageFormat, region: Rect) -> Self {
+        let region = region.clip(img.get_width(), img.get_height());
+        let mut hist = Self {
+            red: [0; 256],
+            green: [0; 256],
+            blue: [0; 256],
+            luminance: [0; 256],
+            count: 0
+        };
+
+        for i in region.x..region.x + region.width {
+            for j in region.y..region.y + region.height {
+                if let Some(pixel) = img.get_pixel(i, j) {
+                    hist.red[pixel.r as usize] += 1;
+                    hist.green[pixel.g as usize] += 1;
+                    hist.blue[pixel.b as usize] += 1;
+                    hist.luminance[pixel.luminance() as usize] += 1;
+                    hist.count += 1;
+                }
+            }
+        }
+
+        hist
+    }
+
+    /// Gets the histogram of a single channel
+    pub fn get(&self, channel: Channel) -> &[u32; 256] {
+        match channel {
+            Channel::Red => &self.red,
+            Channel::Green => &self.green,
+            Channel::Blue => &self.blue,
+            Channel::Luminance => &self.luminance
+        }
+    }
+
+    /// Gets the number of pixels the histogram was computed from
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Gets the cumulative distribution of a single channel
+    pub fn cumulative(&self, channel: Channel) -> [u32; 256] {
+        let mut res = [0u32; 256];
+        let mut sum = 0;
+
+        for (value, freq) in self.get(channel).iter().enumerate() {
+            sum += freq;
+            res[value] = sum;
+        }
+
+        res
+    }
+
+    /// Gets the smallest value that is greater or equal to `percent` % of the pixels
+    /// 
+    /// Returns `None` for an empty histogram
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::Histogram;
+    /// use rusty_imager::models::Channel;
+    /// 
+    /// let img = Image::from_file("<path>").expect("File not found!");
+    /// let hist = Histogram::from_image(&img);
+    /// let p95 = hist.percentile(Channel::Luminance, 95.0);
+    /// ```
+    pub fn percentile(&self, channel: Channel, percent: f32) -> Option<u8> {
+        if self.count == 0 {
+            return None
+        }
+
+        let target = ((percent.clamp(0.0, 100.0) / 100.0) * self.count as f32).ceil().max(1.0) as u32;
+
+        self.cumulative(channel)
+            .iter()
+            .position(|&sum| sum >= target)
+            .map(|value| value as u8)
+    }
+
+    /// Calculates the statistics of a single channel
+    /// 
+    /// Returns `None` for an empty histogram
+    pub fn statistics(&self, channel: Channel) -> Option<Statistics> {
+        Statistics::from_histogram(self.get(channel))
+    }
+
+    /// Picks the cutoff that best separates the channel into two classes (Otsu's method)
+    /// 
+    /// Values lower or equal to the cutoff belong to the background class.
+    /// Returns `None` for an empty histogram
+    pub fn otsu_threshold(&self, channel: Channel) -> Option<u8> {
+        if self.count == 0 {
+            return None
+        }
+
+        let hist = self.get(channel);
+        let total = self.count as f64;
+        let sum: f64 = hist.iter().enumerate().map(|(value, &freq)| value as f64 * freq as f64).sum();
+
+        let mut best = 0u8;
+        let mut best_variance = -1f64;
+        let mut weight_bg = 0f64;
+        let mut sum_bg = 0f64;
+
+        for (value, &freq) in hist.iter().enumerate() {
+            weight_bg += freq as f64;
+            sum_bg += value as f64 * freq as f64;
+
+            let weight_fg = total - weight_bg;
+            if weight_bg == 0.0 || weight_fg == 0.0 {
+                continue;
+            }
+
+            let mean_bg = sum_bg / weight_bg;
+            let mean_fg = (sum - sum_bg) / weight_fg;
+            let variance = weight_bg * weight_fg * (mean_bg - mean_fg).powi(2);
+
+            if variance > best_variance {
+                best_variance = variance;
+                best = value as u8;
+            }
+        }
+
+        Some(best)
+    }
+
+    /// Picks the cutoff with the triangle method, which suits histograms with a single dominant peak
+    /// 
+    /// Values lower or equal to the cutoff belong to the background class.
+    /// Returns `None` for an empty histogram
+    pub fn triangle_threshold(&self, channel: Channel) -> Option<u8> {
+        let hist = self.get(channel);
+        let min = hist.iter().position(|&freq| freq > 0)?;
+        let max = hist.iter().rposition(|&freq| freq > 0)?;
+        let peak = (min..=max).max_by_key(|&value| (hist[value], std::cmp::Reverse(value)))?;
+
+        // The line goes from the peak to the end of the longer tail
+        let end = if peak - min > max - peak { min } else { max };
+        if end == peak {
+            return Some(peak as u8)
+        }
+
+        let (x1, y1) = (peak as f64, hist[peak] as f64);
+        let (x2, y2) = (end as f64, 0f64);
+
+        let mut best = peak;
+        let mut best_distance = -1f64;
+
+        for (value, &freq) in hist.iter().enumerate().take(peak.max(end) + 1).skip(peak.min(end)) {
+            // Unnormalized distance of the bin top from the line
+            let distance = ((y2 - y1) * value as f64 - (x2 - x1) * freq as f64 + x2 * y1 - y2 * x1).abs();
+
+            if distance > best_distance {
+                best_distance = distance;
+                best = value;
+            }
+        }
+
+        Some(best as u8)
+    }
+}
\ No newline at end of file
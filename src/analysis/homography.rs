@@ -0,0 +1,253 @@
+use crate::formats::ImageFormat;
+use crate::models::{Pixel, Point};
+use crate::utils::Random;
+use crate::Image;
+
+/// Projective transformation between two image planes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Homography {
+    /// 3x3 matrix stored row by row, normalized so the last element is 1
+    pub matrix: [f64; 9]
+}
+
+impl Homography {
+    pub fn identity() -> Self {
+        Self {
+            matrix: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+        }
+    }
+
+    /// Least squares fit (normalized DLT) mapping the first point of every pair onto the second
+    ///
+    /// Needs at least 4 pairs, returns `None` for degenerate configurations
+    pub fn from_points(pairs: &[(Point, Point)]) -> Option<Self> {
+        if pairs.len() < 4 {
+            return None;
+        }
+
+        let src: Vec<Point> = pairs.iter().map(|p| p.0).collect();
+        let dst: Vec<Point> = pairs.iter().map(|p| p.1).collect();
+        let t_src = normalization(&src)?;
+        let t_dst = normalization(&dst)?;
+
+        // Normal equations of the 8 unknowns with h33 = 1
+        let mut ata = [[0f64; 8]; 8];
+        let mut atb = [0f64; 8];
+
+        for (s, d) in src.iter().zip(&dst) {
+            let (x, y) = transform(&t_src, s.x as f64, s.y as f64);
+            let (u, v) = transform(&t_dst, d.x as f64, d.y as f64);
+
+            for (row, b) in [
+                ([x, y, 1.0, 0.0, 0.0, 0.0, -u * x, -u * y], u),
+                ([0.0, 0.0, 0.0, x, y, 1.0, -v * x, -v * y], v)
+            ] {
+                for i in 0..8 {
+                    for j in 0..8 {
+                        ata[i][j] += row[i] * row[j];
+                    }
+                    atb[i] += row[i] * b;
+                }
+            }
+        }
+
+        let h = solve(ata, atb)?;
+        let normalized = [h[0], h[1], h[2], h[3], h[4], h[5], h[6], h[7], 1.0];
+        let matrix = multiply(&invert(&t_dst)?, &multiply(&normalized, &t_src));
+
+        if matrix[8].abs() < 1e-12 {
+            return None;
+        }
+
+        Some(Self {
+            matrix: matrix.map(|v| v / matrix[8])
+        })
+    }
+
+    /// Robust fit that ignores mismatched pairs (RANSAC)
+    /// * `threshold` - maximum reprojection error in pixels of an inlier
+    /// * `iterations` - number of random 4 pair samples
+    ///
+    /// Returns the homography refitted to all inliers together with the inlier flag of every pair
+    pub fn ransac(pairs: &[(Point, Point)], threshold: f32, iterations: usize) -> Option<(Self, Vec<bool>)> {
+        if pairs.len() < 4 {
+            return None;
+        }
+
+        let mut random = Random::new(pairs.len() as u64);
+        let mut best: Option<(usize, Vec<bool>)> = None;
+
+        for _ in 0..iterations {
+            let mut sample = [0usize; 4];
+            for i in 0..4 {
+                sample[i] = loop {
+                    let candidate = random.below(pairs.len());
+                    if !sample[..i].contains(&candidate) {
+                        break candidate;
+                    }
+                };
+            }
+
+            let subset: Vec<(Point, Point)> = sample.iter().map(|&i| pairs[i]).collect();
+            let Some(model) = Self::from_points(&subset) else {
+                continue;
+            };
+
+            let inliers = model.inliers(pairs, threshold);
+            let count = inliers.iter().filter(|&&i| i).count();
+
+            if best.as_ref().is_none_or(|(c, _)| count > *c) {
+                best = Some((count, inliers));
+            }
+        }
+
+        let (_, inliers) = best?;
+        let subset: Vec<(Point, Point)> = pairs.iter().zip(&inliers).filter(|(_, &i)| i).map(|(p, _)| *p).collect();
+        let model = Self::from_points(&subset)?;
+        let inliers = model.inliers(pairs, threshold);
+
+        Some((model, inliers))
+    }
+
+    fn inliers(&self, pairs: &[(Point, Point)], threshold: f32) -> Vec<bool> {
+        pairs.iter().map(|(s, d)| self.apply(s).distance(d) <= threshold).collect()
+    }
+
+    /// Maps a point through the transformation
+    pub fn apply(&self, point: &Point) -> Point {
+        let (x, y) = transform(&self.matrix, point.x as f64, point.y as f64);
+
+        Point::new(x as f32, y as f32)
+    }
+
+    /// Reverse transformation, `None` when the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let inverse = invert(&self.matrix)?;
+
+        Some(Self {
+            matrix: inverse.map(|v| v / inverse[8])
+        })
+    }
+
+    /// Renders the image transformed into a new image of the given size with bilinear sampling
+    ///
+    /// Pixels mapped from outside of the source image are black
+    pub fn warp(&self, img: &dyn ImageFormat, width: usize, height: usize) -> Image {
+        let mut res = Image::new(width, height);
+        let Some(inverse) = self.inverse() else {
+            return res;
+        };
+
+        let (src_width, src_height) = (img.get_width() as f32, img.get_height() as f32);
+
+        for y in 0..height {
+            for x in 0..width {
+                let p = inverse.apply(&Point::new(x as f32, y as f32));
+                if !(p.x >= 0.0 && p.y >= 0.0 && p.x <= src_width - 1.0 && p.y <= src_height - 1.0) {
+                    continue;
+                }
+
+                let (x0, y0) = (p.x.floor() as usize, p.y.floor() as usize);
+                let (x1, y1) = ((x0 + 1).min(img.get_width() - 1), (y0 + 1).min(img.get_height() - 1));
+                let (fx, fy) = (p.x - x0 as f32, p.y - y0 as f32);
+
+                let corners = [
+                    (img.get_pixel(x0, y0), (1.0 - fx) * (1.0 - fy)),
+                    (img.get_pixel(x1, y0), fx * (1.0 - fy)),
+                    (img.get_pixel(x0, y1), (1.0 - fx) * fy),
+                    (img.get_pixel(x1, y1), fx * fy)
+                ];
+
+                let mut sum = [0f32; 3];
+                for (pixel, weight) in corners {
+                    let pixel = pixel.cloned().unwrap_or_default();
+                    sum[0] += pixel.r as f32 * weight;
+                    sum[1] += pixel.g as f32 * weight;
+                    sum[2] += pixel.b as f32 * weight;
+                }
+
+                let [r, g, b] = sum.map(|v| v.round().clamp(0.0, 255.0) as u8);
+                res.set_pixel(x, y, Pixel { r, g, b });
+            }
+        }
+
+        res
+    }
+}
+
+fn transform(m: &[f64; 9], x: f64, y: f64) -> (f64, f64) {
+    let w = m[6] * x + m[7] * y + m[8];
+
+    ((m[0] * x + m[1] * y + m[2]) / w, (m[3] * x + m[4] * y + m[5]) / w)
+}
+
+/// Similarity moving the centroid to the origin with the average distance of sqrt(2)
+fn normalization(points: &[Point]) -> Option<[f64; 9]> {
+    let n = points.len() as f64;
+    let cx = points.iter().map(|p| p.x as f64).sum::<f64>() / n;
+    let cy = points.iter().map(|p| p.y as f64).sum::<f64>() / n;
+    let spread = points.iter().map(|p| (p.x as f64 - cx).hypot(p.y as f64 - cy)).sum::<f64>() / n;
+
+    if spread < 1e-9 {
+        return None;
+    }
+
+    let s = std::f64::consts::SQRT_2 / spread;
+    Some([s, 0.0, -s * cx, 0.0, s, -s * cy, 0.0, 0.0, 1.0])
+}
+
+fn multiply(a: &[f64; 9], b: &[f64; 9]) -> [f64; 9] {
+    let mut res = [0f64; 9];
+    for i in 0..3 {
+        for j in 0..3 {
+            res[i * 3 + j] = (0..3).map(|k| a[i * 3 + k] * b[k * 3 + j]).sum();
+        }
+    }
+
+    res
+}
+
+fn invert(m: &[f64; 9]) -> Option<[f64; 9]> {
+    let cofactors = [
+        m[4] * m[8] - m[5] * m[7], m[2] * m[7] - m[1] * m[8], m[1] * m[5] - m[2] * m[4],
+        m[5] * m[6] - m[3] * m[8], m[0] * m[8] - m[2] * m[6], m[2] * m[3] - m[0] * m[5],
+        m[3] * m[7] - m[4] * m[6], m[1] * m[6] - m[0] * m[7], m[0] * m[4] - m[1] * m[3]
+    ];
+
+    let det = m[0] * cofactors[0] + m[1] * cofactors[3] + m[2] * cofactors[6];
+    if det.abs() < 1e-12 {
+        return None;
+    }
+
+    Some(cofactors.map(|c| c / det))
+}
+
+/// Gaussian elimination with partial pivoting
+fn solve(mut a: [[f64; 8]; 8], mut b: [f64; 8]) -> Option<[f64; 8]> {
+    for col in 0..8 {
+        let pivot = (col..8).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-10 {
+            return None;
+        }
+
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let pivot_row = a[col];
+        for row in col + 1..8 {
+            let factor = a[row][col] / pivot_row[col];
+            for (value, p) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * p;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = [0f64; 8];
+    for row in (0..8).rev() {
+        let sum: f64 = (row + 1..8).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+
+    Some(x)
+}
\ No newline at end of file
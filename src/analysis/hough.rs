@@ -0,0 +1,397 @@
+use std::f32::consts::PI;
+
+use crate::drawing::{circle_offsets, draw_circle, draw_line};
+use crate::formats::ImageFormat;
+use crate::models::{Pixel, Point};
+use crate::utils::{luminance_plane, Random};
+
+/// Number of angle bins of the line accumulator (one degree each)
+const THETA_BINS: usize = 180;
+
+/// Infinite line in normal form `x * cos(theta) + y * sin(theta) = rho`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HoughLine {
+    /// Signed distance of the line from the origin
+    pub rho: f32,
+    /// Angle of the line normal in radians (0..PI)
+    pub theta: f32,
+    /// Number of edge pixels lying on the line
+    pub votes: u32
+}
+
+/// Finite line segment found by the probabilistic Hough transform
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineSegment {
+    pub start: Point,
+    pub end: Point,
+    /// Number of edge pixels lying on the segment
+    pub votes: u32
+}
+
+/// Circle found by the Hough circle transform
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HoughCircle {
+    pub center: Point,
+    pub radius: f32,
+    /// Number of edge pixels lying on the circle
+    pub votes: u32
+}
+
+/// Sine and cosine of every accumulator angle
+fn trig_table() -> Vec<(f32, f32)> {
+    (0..THETA_BINS).map(|t| {
+        let theta = t as f32 * PI / THETA_BINS as f32;
+        (theta.cos(), theta.sin())
+    }).collect()
+}
+
+fn edge_mask(img: &dyn ImageFormat) -> Vec<bool> {
+    luminance_plane(img).iter().map(|&v| v > 127).collect()
+}
+
+impl HoughLine {
+    /// Detects straight lines in an edge map (luminance above 127 is an edge), e.g. the output of `Canny`
+    /// * `threshold` - minimum number of edge pixels on a line
+    ///
+    /// Lines are sorted by votes, strongest first
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::HoughLine;
+    /// use rusty_imager::filters::Canny;
+    /// use rusty_imager::models::Colors;
+    ///
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// let mut edges = Image::from_file("<path>").expect("File not found!");
+    /// edges.apply_filter(Canny::new(1.4, 0.1, 0.3));
+    ///
+    /// for line in HoughLine::detect(&edges, 100) {
+    ///     line.draw(&mut img, Colors::RED);
+    /// }
+    /// ```
+    pub fn detect(img: &dyn ImageFormat, threshold: u32) -> Vec<HoughLine> {
+        Self::detect_in_mask(&edge_mask(img), img.get_width(), img.get_height(), threshold)
+    }
+
+    /// Detects straight lines in an edge mask stored row by row
+    pub fn detect_in_mask(mask: &[bool], width: usize, height: usize, threshold: u32) -> Vec<HoughLine> {
+        let trig = trig_table();
+        let offset = (width as f32).hypot(height as f32).ceil() as usize;
+        let rho_bins = offset * 2 + 1;
+        let mut acc = vec![0u32; rho_bins * THETA_BINS];
+
+        for y in 0..height {
+            for x in 0..width {
+                if !mask[y * width + x] {
+                    continue;
+                }
+
+                for (t, &(cos, sin)) in trig.iter().enumerate() {
+                    let r = (x as f32 * cos + y as f32 * sin).round() as isize + offset as isize;
+                    acc[r as usize * THETA_BINS + t] += 1;
+                }
+            }
+        }
+
+        let mut lines = vec![];
+
+        for r in 0..rho_bins {
+            for t in 0..THETA_BINS {
+                let votes = acc[r * THETA_BINS + t];
+                if votes < threshold.max(1) {
+                    continue;
+                }
+
+                // Local maximum over the 3x3 neighbourhood, ties are broken by scan order
+                let mut is_max = true;
+                for (dr, dt) in [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)] {
+                    let nr = r as isize + dr;
+                    let nt = t as isize + dt;
+                    if nr < 0 || nr >= rho_bins as isize || nt < 0 || nt >= THETA_BINS as isize {
+                        continue;
+                    }
+
+                    let other = acc[nr as usize * THETA_BINS + nt as usize];
+                    if other > votes || (other == votes && (dr, dt) < (0, 0)) {
+                        is_max = false;
+                        break;
+                    }
+                }
+
+                if is_max {
+                    lines.push(HoughLine {
+                        rho: r as f32 - offset as f32,
+                        theta: t as f32 * PI / THETA_BINS as f32,
+                        votes
+                    });
+                }
+            }
+        }
+
+        lines.sort_by_key(|l| std::cmp::Reverse(l.votes));
+        lines
+    }
+
+    /// Draws the line across the whole image
+    pub fn draw(&self, img: &mut dyn ImageFormat, color: Pixel) {
+        let (cos, sin) = (self.theta.cos(), self.theta.sin());
+        let length = (img.get_width() as f32).hypot(img.get_height() as f32);
+        let origin = Point::new(self.rho * cos, self.rho * sin);
+
+        draw_line(
+            img,
+            Point::new(origin.x - sin * length, origin.y + cos * length),
+            Point::new(origin.x + sin * length, origin.y - cos * length),
+            color
+        );
+    }
+}
+
+impl LineSegment {
+    /// Detects line segments in an edge map (luminance above 127 is an edge) with the progressive probabilistic Hough transform
+    /// * `threshold` - minimum number of accumulator votes before a line is traced
+    /// * `min_length` - minimum length of a segment in pixels
+    /// * `max_gap` - maximum number of missing edge pixels inside a segment
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::LineSegment;
+    /// use rusty_imager::filters::Canny;
+    ///
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(Canny::new(1.4, 0.1, 0.3));
+    ///
+    /// let table_lines = LineSegment::detect(&img, 50, 100.0, 5);
+    /// ```
+    pub fn detect(img: &dyn ImageFormat, threshold: u32, min_length: f32, max_gap: usize) -> Vec<LineSegment> {
+        Self::detect_in_mask(&edge_mask(img), img.get_width(), img.get_height(), threshold, min_length, max_gap)
+    }
+
+    /// Detects line segments in an edge mask stored row by row
+    pub fn detect_in_mask(mask: &[bool], width: usize, height: usize, threshold: u32, min_length: f32, max_gap: usize) -> Vec<LineSegment> {
+        let trig = trig_table();
+        let offset = (width as f32).hypot(height as f32).ceil() as isize;
+        let mut acc = vec![0u32; (offset as usize * 2 + 1) * THETA_BINS];
+        let mut mask = mask.to_vec();
+        let mut voted = vec![false; mask.len()];
+
+        let rho_index = |x: usize, y: usize, t: usize| {
+            let (cos, sin) = trig[t];
+            ((x as f32 * cos + y as f32 * sin).round() as isize + offset) as usize * THETA_BINS + t
+        };
+
+        // Points are processed in a random (but reproducible) order
+        let mut points: Vec<usize> = (0..mask.len()).filter(|&i| mask[i]).collect();
+        let mut random = Random::new(points.len() as u64);
+        for i in (1..points.len()).rev() {
+            points.swap(i, random.below(i + 1));
+        }
+
+        let mut segments = vec![];
+
+        for idx in points {
+            // Already consumed by a segment
+            if !mask[idx] {
+                continue;
+            }
+
+            let (x, y) = (idx % width, idx / width);
+            let mut best = (0, 0);
+
+            voted[idx] = true;
+            for t in 0..THETA_BINS {
+                let i = rho_index(x, y, t);
+                acc[i] += 1;
+
+                if acc[i] > best.0 {
+                    best = (acc[i], t);
+                }
+            }
+
+            if best.0 < threshold.max(1) {
+                continue;
+            }
+
+            // Walk along the line direction both ways until the gap gets too long
+            let (cos, sin) = trig[best.1];
+            let (dx, dy) = (-sin, cos);
+            let step = dx.abs().max(dy.abs());
+            let (dx, dy) = (dx / step, dy / step);
+
+            let mut ends = [(x, y); 2];
+            for (end, sign) in ends.iter_mut().zip([1.0f32, -1.0]) {
+                let mut gap = 0;
+                let mut k = 1.0;
+
+                loop {
+                    let px = (x as f32 + sign * dx * k).round();
+                    let py = (y as f32 + sign * dy * k).round();
+                    if px < 0.0 || py < 0.0 || px >= width as f32 || py >= height as f32 {
+                        break;
+                    }
+
+                    let (px, py) = (px as usize, py as usize);
+                    if mask[py * width + px] {
+                        *end = (px, py);
+                        gap = 0;
+                    } else {
+                        gap += 1;
+                        if gap > max_gap {
+                            break;
+                        }
+                    }
+
+                    k += 1.0;
+                }
+            }
+
+            let start = Point::new(ends[1].0 as f32, ends[1].1 as f32);
+            let end = Point::new(ends[0].0 as f32, ends[0].1 as f32);
+            let good = start.distance(&end) >= min_length;
+
+            // Consume the pixels of the line, taking back their votes
+            let steps = (ends[0].0 as isize - ends[1].0 as isize).abs().max((ends[0].1 as isize - ends[1].1 as isize).abs());
+            let mut count = 0;
+
+            // Interpolated between the ends so the walk never leaves the image
+            for k in 0..=steps {
+                let t = k as f32 / steps.max(1) as f32;
+                let px = (start.x + (end.x - start.x) * t).round() as usize;
+                let py = (start.y + (end.y - start.y) * t).round() as usize;
+                let i = py * width + px;
+
+                if !mask[i] {
+                    continue;
+                }
+
+                count += 1;
+
+                if good {
+                    if voted[i] {
+                        for t in 0..THETA_BINS {
+                            acc[rho_index(px, py, t)] -= 1;
+                        }
+                    }
+
+                    mask[i] = false;
+                }
+            }
+
+            if good {
+                segments.push(LineSegment { start, end, votes: count });
+            }
+        }
+
+        segments
+    }
+
+    /// Length of the segment
+    pub fn length(&self) -> f32 {
+        self.start.distance(&self.end)
+    }
+
+    /// Draws the segment onto the image
+    pub fn draw(&self, img: &mut dyn ImageFormat, color: Pixel) {
+        draw_line(img, self.start, self.end, color);
+    }
+}
+
+impl HoughCircle {
+    /// Detects circles in an edge map (luminance above 127 is an edge)
+    /// * `min_radius` - smallest searched radius
+    /// * `max_radius` - largest searched radius
+    /// * `threshold` - part of the circumference (0 - 1) that has to be covered by edge pixels
+    ///
+    /// Circles are sorted by the covered part of the circumference, best first
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::HoughCircle;
+    /// use rusty_imager::filters::Canny;
+    ///
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(Canny::new(2.0, 0.1, 0.3));
+    ///
+    /// for circle in HoughCircle::detect(&img, 8, 20, 0.6) {
+    ///     println!("Fiducial at {:?}", circle.center);
+    /// }
+    /// ```
+    pub fn detect(img: &dyn ImageFormat, min_radius: usize, max_radius: usize, threshold: f32) -> Vec<HoughCircle> {
+        Self::detect_in_mask(&edge_mask(img), img.get_width(), img.get_height(), min_radius, max_radius, threshold)
+    }
+
+    /// Detects circles in an edge mask stored row by row
+    pub fn detect_in_mask(mask: &[bool], width: usize, height: usize, min_radius: usize, max_radius: usize, threshold: f32) -> Vec<HoughCircle> {
+        assert!(min_radius >= 1 && min_radius <= max_radius, "Invalid radius range");
+
+        let edges: Vec<(isize, isize)> = (0..mask.len())
+            .filter(|&i| mask[i])
+            .map(|i| ((i % width) as isize, (i / width) as isize))
+            .collect();
+
+        let mut candidates: Vec<(f32, HoughCircle)> = vec![];
+        let mut acc = vec![0u32; width * height];
+
+        for radius in min_radius..=max_radius {
+            let offsets = circle_offsets(radius);
+            let required = ((offsets.len() as f32 * threshold).ceil() as u32).max(1);
+
+            acc.iter_mut().for_each(|v| *v = 0);
+
+            for &(x, y) in &edges {
+                for &(dx, dy) in &offsets {
+                    let cx = x - dx;
+                    let cy = y - dy;
+                    if cx >= 0 && cy >= 0 && cx < width as isize && cy < height as isize {
+                        acc[cy as usize * width + cx as usize] += 1;
+                    }
+                }
+            }
+
+            for cy in 0..height {
+                for cx in 0..width {
+                    let votes = acc[cy * width + cx];
+                    if votes < required {
+                        continue;
+                    }
+
+                    let is_max = (cy.saturating_sub(1)..(cy + 2).min(height)).all(|ny| {
+                        (cx.saturating_sub(1)..(cx + 2).min(width)).all(|nx| {
+                            let other = acc[ny * width + nx];
+                            other < votes || (other == votes && (ny, nx) >= (cy, cx))
+                        })
+                    });
+
+                    if is_max {
+                        candidates.push((votes as f32 / offsets.len() as f32, HoughCircle {
+                            center: Point::new(cx as f32, cy as f32),
+                            radius: radius as f32,
+                            votes
+                        }));
+                    }
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        // Neighbouring radii and centers respond to the same circle
+        let mut circles: Vec<HoughCircle> = vec![];
+        for (_, candidate) in candidates {
+            let duplicate = circles.iter().any(|c| {
+                c.center.distance(&candidate.center) <= 2.0 && (c.radius - candidate.radius).abs() <= 2.0
+            });
+
+            if !duplicate {
+                circles.push(candidate);
+            }
+        }
+
+        circles
+    }
+
+    /// Draws the outline of the circle onto the image
+    pub fn draw(&self, img: &mut dyn ImageFormat, color: Pixel) {
+        draw_circle(img, self.center, self.radius, color);
+    }
+}
\ No newline at end of file
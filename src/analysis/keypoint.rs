@@ -0,0 +1,47 @@
+use crate::models::Point;
+
+/// Distinctive image location found by a feature detector
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keypoint {
+    pub position: Point,
+    /// Detector specific strength, higher is stronger
+    pub score: f32
+}
+
+impl Keypoint {
+    pub fn new(x: f32, y: f32, score: f32) -> Self {
+        Self {
+            position: Point::new(x, y),
+            score
+        }
+    }
+}
+
+/// Keeps the values that are the largest within `radius` and at least `min_score`, strongest first
+pub(super) fn local_maxima(values: &[f32], width: usize, height: usize, radius: usize, min_score: f32) -> Vec<Keypoint> {
+    let mut keypoints = vec![];
+
+    for y in 0..height {
+        for x in 0..width {
+            let value = values[y * width + x];
+            if value < min_score || value <= 0.0 {
+                continue;
+            }
+
+            // Ties are broken by scan order so plateaus give a single keypoint
+            let is_max = (y.saturating_sub(radius)..(y + radius + 1).min(height)).all(|ny| {
+                (x.saturating_sub(radius)..(x + radius + 1).min(width)).all(|nx| {
+                    let other = values[ny * width + nx];
+                    other < value || (other == value && (ny, nx) >= (y, x))
+                })
+            });
+
+            if is_max {
+                keypoints.push(Keypoint::new(x as f32, y as f32, value));
+            }
+        }
+    }
+
+    keypoints.sort_by(|a, b| b.score.total_cmp(&a.score));
+    keypoints
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use super::orb::Descriptor;
+
+/// Pair of descriptors considered to describe the same feature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// Index into the query descriptors
+    pub query: usize,
+    /// Index into the train descriptors
+    pub train: usize,
+    /// Hamming distance of the descriptors
+    pub distance: u32
+}
+
+/// Brute-force matching by Hamming distance with Lowe's ratio test
+/// * `query` - descriptors to find matches for
+/// * `train` - descriptors searched for the matches
+/// * `ratio` - the best match has to be closer than `ratio` times the second best, 1 disables the test
+///
+/// Matches are sorted by distance, closest first
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::analysis::{match_descriptors, Orb};
+///
+/// let first = Image::from_file("<path>").expect("File not found!");
+/// let second = Image::from_file("<path>").expect("File not found!");
+///
+/// let orb = Orb::new(500, 20);
+/// let matches = match_descriptors(&orb.detect(&first), &orb.detect(&second), 0.75);
+/// ```
+pub fn match_descriptors(query: &[Descriptor], train: &[Descriptor], ratio: f32) -> Vec<Match> {
+    let mut matches = vec![];
+
+    for (q, descriptor) in query.iter().enumerate() {
+        let mut best = (0, u32::MAX);
+        let mut second = u32::MAX;
+
+        for (t, other) in train.iter().enumerate() {
+            let distance = descriptor.distance(other);
+
+            if distance < best.1 {
+                second = best.1;
+                best = (t, distance);
+            } else if distance < second {
+                second = distance;
+            }
+        }
+
+        let (t, distance) = best;
+        if distance != u32::MAX && (ratio >= 1.0 || (distance as f32) < ratio * second as f32) {
+            matches.push(Match { query: q, train: t, distance });
+        }
+    }
+
+    matches.sort_by_key(|m| m.distance);
+    matches
+}
\ No newline at end of file
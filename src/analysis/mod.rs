@@ -0,0 +1,33 @@
+mod histogram;
+mod statistics;
+mod gradient;
+mod components;
+mod geometry;
+mod contours;
+mod hough;
+mod keypoint;
+mod corners;
+mod fast;
+mod orb;
+mod matching;
+mod homography;
+mod template;
+mod comparison;
+mod hash;
+
+pub use histogram::Histogram;
+pub use statistics::Statistics;
+pub use gradient::{Gradient, GradientOperator};
+pub use components::{Component, Components};
+pub use geometry::{approximate_polygon, convex_hull, min_area_rect, polygon_area, polygon_perimeter, RotatedRect};
+pub use contours::Contour;
+pub use hough::{HoughCircle, HoughLine, LineSegment};
+pub use keypoint::Keypoint;
+pub use corners::CornerResponse;
+pub use fast::Fast;
+pub use orb::{Descriptor, Orb};
+pub use matching::{match_descriptors, Match};
+pub use homography::Homography;
+pub use template::{MatchMethod, TemplateLocation, TemplateMatch};
+pub use comparison::{difference, ms_ssim, mse, psnr, ssim, Difference};
+pub use hash::ImageHash;
\ No newline at end of file
@@ -0,0 +1,153 @@
+use std::f32::consts::PI;
+
+use crate::formats::ImageFormat;
+use crate::utils::{calculate_gaussian_kernel_1d, luminance_plane, separable_convolution, Random};
+
+use super::corners::CornerResponse;
+use super::fast::Fast;
+use super::keypoint::Keypoint;
+
+/// Radius of the patch used for the orientation and the binary tests
+const PATCH_RADIUS: isize = 15;
+
+/// Keypoints closer to the border than this cannot fit the rotated patch
+const BORDER: usize = 22;
+
+/// Number of binary tests (bits) of a descriptor
+const TESTS: usize = 256;
+
+/// Oriented binary descriptor of a keypoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct Descriptor {
+    pub keypoint: Keypoint,
+    /// Orientation of the patch in radians (-π - π)
+    pub angle: f32,
+    bits: [u64; TESTS / 64]
+}
+
+impl Descriptor {
+    /// Results of the binary tests
+    pub fn bits(&self) -> &[u64] {
+        &self.bits
+    }
+
+    /// Hamming distance between two descriptors
+    pub fn distance(&self, other: &Descriptor) -> u32 {
+        self.bits.iter().zip(&other.bits).map(|(a, b)| (a ^ b).count_ones()).sum()
+    }
+}
+
+/// ORB-style feature extractor, FAST keypoints ranked by the Harris response with rotated BRIEF descriptors
+///
+/// Works on a single scale, so the features are invariant to rotation but not to scaling
+pub struct Orb {
+    max_features: usize,
+    fast_threshold: u8
+}
+
+impl Orb {
+    /// * `max_features` - maximum number of returned features
+    /// * `fast_threshold` - threshold of the FAST detector
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::{match_descriptors, Homography, Orb};
+    /// use rusty_imager::formats::ImageFormat;
+    ///
+    /// let first = Image::from_file("<path>").expect("File not found!");
+    /// let second = Image::from_file("<path>").expect("File not found!");
+    ///
+    /// let orb = Orb::new(500, 20);
+    /// let a = orb.detect(&first);
+    /// let b = orb.detect(&second);
+    ///
+    /// let pairs: Vec<_> = match_descriptors(&a, &b, 0.8).iter()
+    ///     .map(|m| (a[m.query].keypoint.position, b[m.train].keypoint.position))
+    ///     .collect();
+    ///
+    /// if let Some((homography, _)) = Homography::ransac(&pairs, 3.0, 1000) {
+    ///     let aligned = homography.warp(&first, second.get_width(), second.get_height());
+    /// }
+    /// ```
+    pub fn new(max_features: usize, fast_threshold: u8) -> Self {
+        Self { max_features, fast_threshold }
+    }
+
+    /// Finds the keypoints and computes their descriptors, strongest first
+    pub fn detect(&self, img: &dyn ImageFormat) -> Vec<Descriptor> {
+        let width = img.get_width();
+        let height = img.get_height();
+
+        let harris = CornerResponse::harris(img, 0.04, 1.0);
+        let mut keypoints: Vec<Keypoint> = Fast::new(self.fast_threshold).detect(img).into_iter()
+            .filter(|k| {
+                let (x, y) = (k.position.x as usize, k.position.y as usize);
+                x >= BORDER && y >= BORDER && x + BORDER < width && y + BORDER < height
+            })
+            .map(|k| {
+                let idx = k.position.y as usize * width + k.position.x as usize;
+                Keypoint { score: harris.values()[idx], ..k }
+            })
+            .collect();
+
+        keypoints.sort_by(|a, b| b.score.total_cmp(&a.score));
+        keypoints.truncate(self.max_features);
+
+        // The binary tests are sensitive to noise, so they compare a smoothed image
+        let plane: Vec<f32> = luminance_plane(img).iter().map(|&v| v as f32).collect();
+        let plane = separable_convolution(&plane, width, height, &calculate_gaussian_kernel_1d(2.0, 9));
+        let pattern = test_pattern();
+
+        keypoints.into_iter().map(|keypoint| {
+            let (x, y) = (keypoint.position.x as isize, keypoint.position.y as isize);
+            let at = |dx: isize, dy: isize| plane[(y + dy) as usize * width + (x + dx) as usize];
+
+            // Intensity centroid of the circular patch
+            let mut m10 = 0f32;
+            let mut m01 = 0f32;
+            for dy in -PATCH_RADIUS..=PATCH_RADIUS {
+                for dx in -PATCH_RADIUS..=PATCH_RADIUS {
+                    if dx * dx + dy * dy <= PATCH_RADIUS * PATCH_RADIUS {
+                        let value = at(dx, dy);
+                        m10 += dx as f32 * value;
+                        m01 += dy as f32 * value;
+                    }
+                }
+            }
+
+            let angle = m01.atan2(m10);
+            let (sin, cos) = angle.sin_cos();
+            let rotate = |(px, py): (f32, f32)| {
+                ((px * cos - py * sin).round() as isize, (px * sin + py * cos).round() as isize)
+            };
+
+            let mut bits = [0u64; TESTS / 64];
+            for (i, &(a, b)) in pattern.iter().enumerate() {
+                let (ax, ay) = rotate(a);
+                let (bx, by) = rotate(b);
+
+                if at(ax, ay) < at(bx, by) {
+                    bits[i / 64] |= 1 << (i % 64);
+                }
+            }
+
+            Descriptor { keypoint, angle, bits }
+        }).collect()
+    }
+}
+
+/// Point pairs of the binary tests drawn from an isotropic Gaussian around the keypoint
+fn test_pattern() -> Vec<((f32, f32), (f32, f32))> {
+    let mut random = Random::new(31);
+    let sigma = (2 * PATCH_RADIUS + 1) as f32 / 5.0;
+    let limit = PATCH_RADIUS as f32;
+
+    // Box-Muller transform
+    let mut gaussian = || {
+        let u = random.next_f32().max(f32::EPSILON);
+        let v = random.next_f32();
+        ((-2.0 * u.ln()).sqrt() * (2.0 * PI * v).cos() * sigma).round().clamp(-limit, limit)
+    };
+
+    (0..TESTS).map(|_| ((gaussian(), gaussian()), (gaussian(), gaussian()))).collect()
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+use crate::formats::ImageFormat;
+use crate::models::{Channel, Rect};
+
+use super::Histogram;
+
+/// Summary statistics of a single channel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Statistics {
+    pub min: u8,
+    pub max: u8,
+    pub mean: f32,
+    pub median: u8,
+    pub std_dev: f32
+}
+
+impl Statistics {
+    /// Calculates the statistics of a channel over the whole image
+    /// 
+    /// Returns `None` for an empty image
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::Statistics;
+    /// use rusty_imager::models::Channel;
+    /// 
+    /// let img = Image::from_file("<path>").expect("File not found!");
+    /// let stats = Statistics::from_image(&img, Channel::Luminance).unwrap();
+    /// println!("mean: {}, std dev: {}", stats.mean, stats.std_dev);
+    /// ```
+    pub fn from_image(img: &dyn ImageFormat, channel: Channel) -> Option<Self> {
+        Histogram::from_image(img).statistics(channel)
+    }
+
+    /// Calculates the statistics of a channel over the part of the image covered by `region`
+    /// 
+    /// Returns `None` if the region doesn't overlap the image
+    pub fn from_region(img: &dyn ImageFormat, region: Rect, channel: Channel) -> Option<Self> {
+        Histogram::from_region(img, region).statistics(channel)
+    }
+
+    /// Calculates the statistics from a 256 bin histogram
+    pub fn from_histogram(hist: &[u32; 256]) -> Option<Self> {
+        let count: u64 = hist.iter().map(|&freq| freq as u64).sum();
+
+        if count == 0 {
+            return None
+        }
+
+        let min = hist.iter().position(|&freq| freq > 0)? as u8;
+        let max = hist.iter().rposition(|&freq| freq > 0)? as u8;
+
+        let sum: f64 = hist.iter().enumerate().map(|(value, &freq)| value as f64 * freq as f64).sum();
+        let mean = sum / count as f64;
+
+        let variance: f64 = hist.iter()
+            .enumerate()
+            .map(|(value, &freq)| (value as f64 - mean).powi(2) * freq as f64)
+            .sum::<f64>() / count as f64;
+
+        let half = count.div_ceil(2);
+        let mut cumulative = 0u64;
+        let mut median = 0u8;
+
+        for (value, &freq) in hist.iter().enumerate() {
+            cumulative += freq as u64;
+
+            if cumulative >= half {
+                median = value as u8;
+                break;
+            }
+        }
+
+        Some(Self {
+            min,
+            max,
+            mean: mean as f32,
+            median,
+            std_dev: variance.sqrt() as f32
+        })
+    }
+}
\ No newline at end of file
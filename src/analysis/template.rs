@@ -0,0 +1,354 @@
+use crate::formats::ImageFormat;
+use crate::models::{Pixel, Rect};
+use crate::Image;
+
+/// Similarity measure between the template and an image window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMethod {
+    /// Mean of the squared differences, lower is better
+    SquaredDifference,
+    /// Normalized cross-correlation (0 - 1), higher is better
+    CrossCorrelation,
+    /// Zero-mean normalized cross-correlation (-1 - 1), higher is better, robust to brightness changes
+    ZeroMeanCrossCorrelation
+}
+
+impl MatchMethod {
+    fn is_better(&self, a: f32, b: f32) -> bool {
+        match self {
+            MatchMethod::SquaredDifference => a < b,
+            _ => a > b
+        }
+    }
+}
+
+/// Position of the template in the image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TemplateLocation {
+    /// Area covered by the template
+    pub bounds: Rect,
+    pub score: f32
+}
+
+/// Color channels of an image with running sums for fast window statistics
+struct Samples {
+    width: usize,
+    height: usize,
+    /// Red, green and blue of every pixel row by row
+    values: Vec<f32>,
+    /// Summed area tables of the sum and the sum of squares of every pixel's channels
+    sum: Vec<f64>,
+    sum_sq: Vec<f64>
+}
+
+impl Samples {
+    fn from_image(img: &dyn ImageFormat) -> Self {
+        let width = img.get_width();
+        let height = img.get_height();
+        let mut values = Vec::with_capacity(width * height * 3);
+
+        for y in 0..height {
+            for x in 0..width {
+                let pixel = img.get_pixel(x, y).cloned().unwrap_or_default();
+                values.extend([pixel.r as f32, pixel.g as f32, pixel.b as f32]);
+            }
+        }
+
+        Self::from_values(values, width, height)
+    }
+
+    fn from_values(values: Vec<f32>, width: usize, height: usize) -> Self {
+        let stride = width + 1;
+        let mut sum = vec![0f64; stride * (height + 1)];
+        let mut sum_sq = vec![0f64; stride * (height + 1)];
+
+        for y in 0..height {
+            let mut row = 0f64;
+            let mut row_sq = 0f64;
+
+            for x in 0..width {
+                for c in 0..3 {
+                    let v = values[(y * width + x) * 3 + c] as f64;
+                    row += v;
+                    row_sq += v * v;
+                }
+
+                sum[(y + 1) * stride + x + 1] = sum[y * stride + x + 1] + row;
+                sum_sq[(y + 1) * stride + x + 1] = sum_sq[y * stride + x + 1] + row_sq;
+            }
+        }
+
+        Self { width, height, values, sum, sum_sq }
+    }
+
+    /// Half sized copy, every pixel is the average of a 2x2 block
+    fn downsample(&self) -> Self {
+        let width = self.width / 2;
+        let height = self.height / 2;
+        let mut values = Vec::with_capacity(width * height * 3);
+
+        for y in 0..height {
+            for x in 0..width {
+                for c in 0..3 {
+                    let at = |dx: usize, dy: usize| self.values[((2 * y + dy) * self.width + 2 * x + dx) * 3 + c];
+                    values.push((at(0, 0) + at(1, 0) + at(0, 1) + at(1, 1)) / 4.0);
+                }
+            }
+        }
+
+        Self::from_values(values, width, height)
+    }
+
+    /// Sum and sum of squares of the window
+    fn window(&self, x: usize, y: usize, width: usize, height: usize) -> (f64, f64) {
+        let stride = self.width + 1;
+        let area = |table: &[f64]| {
+            table[(y + height) * stride + x + width] - table[y * stride + x + width]
+                - table[(y + height) * stride + x] + table[y * stride + x]
+        };
+
+        (area(&self.sum), area(&self.sum_sq))
+    }
+}
+
+/// Score map of a template placed at every position of an image
+pub struct TemplateMatch {
+    width: usize,
+    height: usize,
+    template_width: usize,
+    template_height: usize,
+    method: MatchMethod,
+    scores: Vec<f32>
+}
+
+impl TemplateMatch {
+    /// Scores every position where the template fits completely into the image
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::{MatchMethod, TemplateMatch};
+    ///
+    /// let screenshot = Image::from_file("<path>").expect("File not found!");
+    /// let button = Image::from_file("<path>").expect("File not found!");
+    ///
+    /// let result = TemplateMatch::new(&screenshot, &button, MatchMethod::ZeroMeanCrossCorrelation);
+    /// for location in result.matches(0.9, 10) {
+    ///     println!("Button at {:?}", location.bounds);
+    /// }
+    /// ```
+    pub fn new(img: &dyn ImageFormat, template: &dyn ImageFormat, method: MatchMethod) -> Self {
+        let img = Samples::from_image(img);
+        let template = Samples::from_image(template);
+
+        assert!(template.width > 0 && template.height > 0, "Template is empty");
+        assert!(template.width <= img.width && template.height <= img.height, "Template is larger than the image");
+
+        let width = img.width - template.width + 1;
+        let height = img.height - template.height + 1;
+        let mut scores = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                scores.push(score_at(&img, &template, x, y, method));
+            }
+        }
+
+        Self {
+            width,
+            height,
+            template_width: template.width,
+            template_height: template.height,
+            method,
+            scores
+        }
+    }
+
+    /// Finds the best position with a coarse to fine search over an image pyramid
+    /// * `levels` - number of times both images are halved, the template should stay at least a few pixels large
+    ///
+    /// Much faster than scoring the full image but only the single best match is found
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::analysis::{MatchMethod, TemplateMatch};
+    ///
+    /// let screenshot = Image::from_file("<path>").expect("File not found!");
+    /// let logo = Image::from_file("<path>").expect("File not found!");
+    ///
+    /// let location = TemplateMatch::locate(&screenshot, &logo, MatchMethod::CrossCorrelation, 3);
+    /// ```
+    pub fn locate(img: &dyn ImageFormat, template: &dyn ImageFormat, method: MatchMethod, levels: usize) -> Option<TemplateLocation> {
+        let mut images = vec![Samples::from_image(img)];
+        let mut templates = vec![Samples::from_image(template)];
+
+        if templates[0].width == 0 || templates[0].height == 0 || templates[0].width > images[0].width || templates[0].height > images[0].height {
+            return None;
+        }
+
+        for _ in 0..levels {
+            let template = templates.last().unwrap().downsample();
+            if template.width < 2 || template.height < 2 {
+                break;
+            }
+
+            images.push(images.last().unwrap().downsample());
+            templates.push(template);
+        }
+
+        // Full search on the coarsest level, then refinement around the upscaled position
+        let mut candidate: Option<(usize, usize)> = None;
+        let mut best = (0, 0, 0f32);
+
+        for (img, template) in images.iter().zip(&templates).rev() {
+            let max_x = img.width - template.width;
+            let max_y = img.height - template.height;
+
+            let (xs, ys) = match candidate {
+                Some((cx, cy)) => (
+                    (cx * 2).saturating_sub(2)..=(cx * 2 + 2).min(max_x),
+                    (cy * 2).saturating_sub(2)..=(cy * 2 + 2).min(max_y)
+                ),
+                None => (0..=max_x, 0..=max_y)
+            };
+
+            let mut level_best: Option<(usize, usize, f32)> = None;
+            for y in ys {
+                for x in xs.clone() {
+                    let score = score_at(img, template, x, y, method);
+                    if level_best.is_none_or(|(_, _, s)| method.is_better(score, s)) {
+                        level_best = Some((x, y, score));
+                    }
+                }
+            }
+
+            best = level_best?;
+            candidate = Some((best.0, best.1));
+        }
+
+        Some(TemplateLocation {
+            bounds: Rect::new(best.0, best.1, templates[0].width, templates[0].height),
+            score: best.2
+        })
+    }
+
+    /// Width of the score map (image width - template width + 1)
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the score map (image height - template height + 1)
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Score of every template position (its top left corner) stored row by row
+    pub fn scores(&self) -> &[f32] {
+        &self.scores
+    }
+
+    /// Position with the best score
+    pub fn best(&self) -> TemplateLocation {
+        let mut best = 0;
+        for (idx, &score) in self.scores.iter().enumerate() {
+            if self.method.is_better(score, self.scores[best]) {
+                best = idx;
+            }
+        }
+
+        self.location(best)
+    }
+
+    /// Non-overlapping positions with a score at least as good as the threshold, best first
+    /// * `threshold` - maximum score for `SquaredDifference`, minimum score for the correlations
+    /// * `max_count` - maximum number of returned positions
+    pub fn matches(&self, threshold: f32, max_count: usize) -> Vec<TemplateLocation> {
+        let mut candidates: Vec<usize> = (0..self.scores.len())
+            .filter(|&i| self.scores[i] == threshold || self.method.is_better(self.scores[i], threshold))
+            .collect();
+
+        candidates.sort_by(|&a, &b| {
+            let (a, b) = (self.scores[a], self.scores[b]);
+            match self.method {
+                MatchMethod::SquaredDifference => a.total_cmp(&b),
+                _ => b.total_cmp(&a)
+            }
+        });
+
+        let mut locations: Vec<TemplateLocation> = vec![];
+        for idx in candidates {
+            if locations.len() >= max_count {
+                break;
+            }
+
+            let location = self.location(idx);
+            if !locations.iter().any(|l| l.bounds.intersects(&location.bounds)) {
+                locations.push(location);
+            }
+        }
+
+        locations
+    }
+
+    /// Renders the score map, white is the best match
+    pub fn to_image(&self) -> Image {
+        let min = self.scores.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = self.scores.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let range = if max > min { max - min } else { 1.0 };
+        let mut img = Image::new(self.width, self.height);
+
+        for (idx, score) in self.scores.iter().enumerate() {
+            let mut value = (score - min) / range;
+            if self.method == MatchMethod::SquaredDifference {
+                value = 1.0 - value;
+            }
+
+            let gray = (value * 255.0).round() as u8;
+            img.set_pixel(idx % self.width, idx / self.width, Pixel { r: gray, g: gray, b: gray });
+        }
+
+        img
+    }
+
+    fn location(&self, idx: usize) -> TemplateLocation {
+        TemplateLocation {
+            bounds: Rect::new(idx % self.width, idx / self.width, self.template_width, self.template_height),
+            score: self.scores[idx]
+        }
+    }
+}
+
+/// Score of the template with its top left corner at x and y
+fn score_at(img: &Samples, template: &Samples, x: usize, y: usize, method: MatchMethod) -> f32 {
+    let row = template.width * 3;
+    let mut cross = 0f64;
+
+    for ty in 0..template.height {
+        let start = ((y + ty) * img.width + x) * 3;
+        let window = &img.values[start..start + row];
+        let pattern = &template.values[ty * row..(ty + 1) * row];
+
+        cross += window.iter().zip(pattern).map(|(a, b)| (a * b) as f64).sum::<f64>();
+    }
+
+    let n = (template.width * template.height * 3) as f64;
+    let (img_sum, img_sq) = img.window(x, y, template.width, template.height);
+    let (tpl_sum, tpl_sq) = template.window(0, 0, template.width, template.height);
+
+    let score = match method {
+        MatchMethod::SquaredDifference => ((img_sq - 2.0 * cross + tpl_sq) / n).max(0.0),
+        MatchMethod::CrossCorrelation => {
+            let norm = (img_sq * tpl_sq).sqrt();
+            if norm > 0.0 { cross / norm } else { 0.0 }
+        }
+        MatchMethod::ZeroMeanCrossCorrelation => {
+            let img_var = img_sq - img_sum * img_sum / n;
+            let tpl_var = tpl_sq - tpl_sum * tpl_sum / n;
+            let norm = (img_var * tpl_var).sqrt();
+
+            // Flat windows or templates have no defined correlation
+            if norm > 1e-6 { (cross - img_sum * tpl_sum / n) / norm } else { 0.0 }
+        }
+    };
+
+    score as f32
+}
\ No newline at end of file
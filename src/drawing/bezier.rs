@@ -0,0 +1,63 @@
+use crate::formats::ImageFormat;
+use crate::models::{Pixel, Point};
+
+use super::line::draw_line;
+
+/// Draws a quadratic Bezier curve from `start` to `end` bent towards `control`
+pub fn draw_quadratic_bezier(img: &mut dyn ImageFormat, start: Point, control: Point, end: Point, color: Pixel) {
+    let segments = segment_count(&[start, control, end]);
+    let points: Vec<Point> = (0..=segments).map(|i| {
+        let t = i as f32 / segments as f32;
+        let u = 1.0 - t;
+
+        Point::new(
+            u * u * start.x + 2.0 * u * t * control.x + t * t * end.x,
+            u * u * start.y + 2.0 * u * t * control.y + t * t * end.y
+        )
+    }).collect();
+
+    draw_polyline(img, &points, color);
+}
+
+/// Draws a cubic Bezier curve from `start` to `end` shaped by two control points
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::drawing::draw_cubic_bezier;
+/// use rusty_imager::models::{Colors, Point};
+///
+/// let mut img = Image::from_file("<path>").expect("File not found!");
+/// draw_cubic_bezier(
+///     &mut img,
+///     Point::new(10.0, 80.0), Point::new(40.0, 10.0), Point::new(70.0, 10.0), Point::new(100.0, 80.0),
+///     Colors::WHITE
+/// );
+/// ```
+pub fn draw_cubic_bezier(img: &mut dyn ImageFormat, start: Point, control1: Point, control2: Point, end: Point, color: Pixel) {
+    let segments = segment_count(&[start, control1, control2, end]);
+    let points: Vec<Point> = (0..=segments).map(|i| {
+        let t = i as f32 / segments as f32;
+        let u = 1.0 - t;
+        let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+
+        Point::new(
+            a * start.x + b * control1.x + c * control2.x + d * end.x,
+            a * start.y + b * control1.y + c * control2.y + d * end.y
+        )
+    }).collect();
+
+    draw_polyline(img, &points, color);
+}
+
+/// Enough straight segments that each of them spans only a few pixels
+fn segment_count(control: &[Point]) -> usize {
+    let length: f32 = control.windows(2).map(|w| w[0].distance(&w[1])).sum();
+
+    ((length / 3.0).ceil() as usize).max(1)
+}
+
+fn draw_polyline(img: &mut dyn ImageFormat, points: &[Point], color: Pixel) {
+    for pair in points.windows(2) {
+        draw_line(img, pair[0], pair[1], color.clone());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,569 @@
+use super::truetype::{read_u16, OutlinePoint};
+
+/// Nesting limit of subroutine calls, the same as the CFF specification allows
+const MAX_SUBR_DEPTH: usize = 10;
+
+/// Limit of operators executed for one glyph, protects against subroutines calling each other many times
+const MAX_OPERATIONS: usize = 20000;
+
+/// Operand stack limit of Type 2 charstrings
+const MAX_STACK: usize = 48;
+
+/// Array of variable sized objects, the basic structure of the CFF table
+#[derive(Debug, Clone, Copy)]
+struct Index {
+    count: usize,
+    off_size: usize,
+    offsets: usize,
+    /// Position before the first object, the offsets count from one
+    base: usize,
+    end: usize
+}
+
+impl Index {
+    fn read(data: &[u8], pos: usize) -> Option<Self> {
+        let count = read_u16(data, pos)? as usize;
+        if count == 0 {
+            return Some(Self { count, off_size: 0, offsets: pos + 2, base: pos + 2, end: pos + 2 });
+        }
+
+        let off_size = *data.get(pos + 2)? as usize;
+        if !(1..=4).contains(&off_size) {
+            return None;
+        }
+
+        let offsets = pos + 3;
+        let base = offsets + (count + 1) * off_size - 1;
+        let end = base + read_offset(data, offsets + count * off_size, off_size)?;
+        if end > data.len() {
+            return None;
+        }
+
+        Some(Self { count, off_size, offsets, base, end })
+    }
+
+    fn get<'a>(&self, data: &'a [u8], i: usize) -> Option<&'a [u8]> {
+        if i >= self.count {
+            return None;
+        }
+
+        let start = read_offset(data, self.offsets + i * self.off_size, self.off_size)?;
+        let end = read_offset(data, self.offsets + (i + 1) * self.off_size, self.off_size)?;
+
+        data.get(self.base + start..self.base + end)
+    }
+
+    /// Number added to the subroutine numbers, lets small fonts use one byte operands
+    fn bias(&self) -> i32 {
+        match self.count {
+            0..1240 => 107,
+            1240..33900 => 1131,
+            _ => 32768
+        }
+    }
+}
+
+/// Outlines of the `CFF ` table of an OpenType font (Type 2 charstrings)
+pub(super) struct Cff {
+    char_strings: Index,
+    global_subrs: Index,
+    /// Local subroutines of every font dict, only one for fonts that are not CID-keyed
+    local_subrs: Vec<Index>,
+    /// Position of the table that assigns font dicts to the glyphs of CID-keyed fonts
+    fd_select: Option<usize>
+}
+
+impl Cff {
+    /// * `start` - offset of the `CFF ` table in the font file
+    pub(super) fn parse(data: &[u8], start: usize) -> Option<Self> {
+        let header_size = *data.get(start + 2)? as usize;
+        let names = Index::read(data, start + header_size)?;
+        let top_dicts = Index::read(data, names.end)?;
+        let strings = Index::read(data, top_dicts.end)?;
+        let global_subrs = Index::read(data, strings.end)?;
+
+        let top = parse_dict(top_dicts.get(data, 0)?)?;
+        let operand = |op: u16| top.iter().find(|(o, _)| *o == op).map(|(_, operands)| operands.as_slice());
+        let offset = |op: u16| operand(op).and_then(|o| o.first()).map(|&v| start + v as usize);
+
+        // Type 1 charstrings are not used by OpenType fonts
+        if operand(1206).is_some_and(|o| o.first() != Some(&2.0)) {
+            return None;
+        }
+
+        let char_strings = Index::read(data, offset(17)?)?;
+
+        let (local_subrs, fd_select) = match offset(1236) {
+            Some(fd_array) => {
+                let fd_array = Index::read(data, fd_array)?;
+                let subrs = (0..fd_array.count)
+                    .map(|i| private_subrs(data, start, &parse_dict(fd_array.get(data, i)?)?))
+                    .collect::<Option<Vec<Index>>>()?;
+
+                (subrs, Some(offset(1237)?))
+            }
+
+            None => (vec![private_subrs(data, start, &top)?], None)
+        };
+
+        Some(Self {
+            char_strings,
+            global_subrs,
+            local_subrs,
+            fd_select
+        })
+    }
+
+    pub(super) fn glyph_count(&self) -> usize {
+        self.char_strings.count
+    }
+
+    /// Contours of the glyph in font units, curves are made of two cubic control points followed by an on curve point
+    pub(super) fn outline(&self, data: &[u8], glyph: u16) -> Option<Vec<Vec<OutlinePoint>>> {
+        let local_subrs = match self.fd_select {
+            Some(fd_select) => *self.local_subrs.get(font_dict(data, fd_select, glyph)?)?,
+            None => *self.local_subrs.first()?
+        };
+
+        let mut charstring = Charstring {
+            data,
+            global_subrs: self.global_subrs,
+            local_subrs,
+            stack: Vec::with_capacity(MAX_STACK),
+            stems: 0,
+            width_parsed: false,
+            x: 0.0,
+            y: 0.0,
+            contours: vec![],
+            operations: 0
+        };
+
+        charstring.run(self.char_strings.get(data, glyph as usize)?, 0)?;
+
+        Some(charstring.contours)
+    }
+}
+
+/// Local subroutines of the private dict referenced by a top or font dict, empty when there are none
+fn private_subrs(data: &[u8], start: usize, dict: &[(u16, Vec<f64>)]) -> Option<Index> {
+    let empty = Index { count: 0, off_size: 0, offsets: 0, base: 0, end: 0 };
+    let Some((_, private)) = dict.iter().find(|(op, _)| *op == 18) else {
+        return Some(empty);
+    };
+
+    let [size, offset] = private.as_slice() else {
+        return None;
+    };
+
+    let private_start = start + *offset as usize;
+    let private = parse_dict(data.get(private_start..private_start + *size as usize)?)?;
+
+    match private.iter().find(|(op, _)| *op == 19) {
+        Some((_, subrs)) => Index::read(data, private_start + *subrs.first()? as usize),
+        None => Some(empty)
+    }
+}
+
+/// Font dict of a glyph of a CID-keyed font
+fn font_dict(data: &[u8], fd_select: usize, glyph: u16) -> Option<usize> {
+    match *data.get(fd_select)? {
+        0 => data.get(fd_select + 1 + glyph as usize).map(|&fd| fd as usize),
+
+        3 => {
+            let ranges = read_u16(data, fd_select + 1)? as usize;
+            let mut fd = None;
+
+            for i in 0..ranges {
+                let range = fd_select + 3 + i * 3;
+                if read_u16(data, range)? > glyph {
+                    break;
+                }
+
+                fd = Some(*data.get(range + 2)? as usize);
+            }
+
+            fd
+        }
+
+        _ => None
+    }
+}
+
+/// Operators of a DICT with their operands, two byte operators are stored as 1200 + the second byte
+fn parse_dict(bytes: &[u8]) -> Option<Vec<(u16, Vec<f64>)>> {
+    let mut res = vec![];
+    let mut operands = vec![];
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let b0 = bytes[pos];
+        pos += 1;
+
+        match b0 {
+            0..=21 => {
+                let op = if b0 == 12 {
+                    pos += 1;
+                    1200 + *bytes.get(pos - 1)? as u16
+                } else {
+                    b0 as u16
+                };
+
+                res.push((op, std::mem::take(&mut operands)));
+            }
+
+            28 => {
+                pos += 2;
+                operands.push(i16::from_be_bytes([*bytes.get(pos - 2)?, *bytes.get(pos - 1)?]) as f64);
+            }
+
+            29 => {
+                pos += 4;
+                operands.push(i32::from_be_bytes(bytes.get(pos - 4..pos)?.try_into().ok()?) as f64);
+            }
+
+            30 => {
+                // Real number stored as nibbles, two per byte
+                let mut text = String::new();
+                'number: loop {
+                    let byte = *bytes.get(pos)?;
+                    pos += 1;
+
+                    for nibble in [byte >> 4, byte & 0x0F] {
+                        match nibble {
+                            0..=9 => text.push((b'0' + nibble) as char),
+                            0x0A => text.push('.'),
+                            0x0B => text.push('E'),
+                            0x0C => text.push_str("E-"),
+                            0x0E => text.push('-'),
+                            0x0F => break 'number,
+                            _ => return None
+                        }
+                    }
+                }
+
+                operands.push(text.parse().ok()?);
+            }
+
+            32..=246 => operands.push(b0 as f64 - 139.0),
+
+            247..=250 => {
+                pos += 1;
+                operands.push((b0 as f64 - 247.0) * 256.0 + *bytes.get(pos - 1)? as f64 + 108.0);
+            }
+
+            251..=254 => {
+                pos += 1;
+                operands.push(-(b0 as f64 - 251.0) * 256.0 - *bytes.get(pos - 1)? as f64 - 108.0);
+            }
+
+            _ => return None
+        }
+    }
+
+    Some(res)
+}
+
+fn read_offset(data: &[u8], pos: usize, size: usize) -> Option<usize> {
+    data.get(pos..pos + size).map(|bytes| bytes.iter().fold(0, |acc, &b| acc << 8 | b as usize))
+}
+
+/// State of the Type 2 charstring interpreter
+struct Charstring<'a> {
+    data: &'a [u8],
+    global_subrs: Index,
+    local_subrs: Index,
+    stack: Vec<f32>,
+    /// Number of stem hints, decides the size of the hint masks
+    stems: usize,
+    /// The advance width can precede the operands of the first stack clearing operator
+    width_parsed: bool,
+    x: f32,
+    y: f32,
+    contours: Vec<Vec<OutlinePoint>>,
+    operations: usize
+}
+
+impl Charstring<'_> {
+    /// Executes the charstring, returns whether it reached its end (`endchar`)
+    fn run(&mut self, code: &[u8], depth: usize) -> Option<bool> {
+        let mut pos = 0;
+
+        while pos < code.len() {
+            let b0 = code[pos];
+            pos += 1;
+
+            match b0 {
+                28 => {
+                    pos += 2;
+                    self.push(i16::from_be_bytes([*code.get(pos - 2)?, *code.get(pos - 1)?]) as f32)?;
+                }
+
+                32..=246 => self.push(b0 as f32 - 139.0)?,
+
+                247..=250 => {
+                    pos += 1;
+                    self.push((b0 as f32 - 247.0) * 256.0 + *code.get(pos - 1)? as f32 + 108.0)?;
+                }
+
+                251..=254 => {
+                    pos += 1;
+                    self.push(-(b0 as f32 - 251.0) * 256.0 - *code.get(pos - 1)? as f32 - 108.0)?;
+                }
+
+                255 => {
+                    // 16.16 fixed point
+                    pos += 4;
+                    self.push(i32::from_be_bytes(code.get(pos - 4..pos)?.try_into().ok()?) as f32 / 65536.0)?;
+                }
+
+                _ => {
+                    self.operations += 1;
+                    if self.operations > MAX_OPERATIONS {
+                        return None;
+                    }
+
+                    let op = if b0 == 12 {
+                        pos += 1;
+                        1200 + *code.get(pos - 1)? as u16
+                    } else {
+                        b0 as u16
+                    };
+
+                    match op {
+                        // hstem, vstem, hstemhm, vstemhm
+                        1 | 3 | 18 | 23 => {
+                            self.parse_width(self.stack.len() % 2 == 1);
+                            self.stems += self.stack.len() / 2;
+                            self.stack.clear();
+                        }
+
+                        // hintmask, cntrmask, the operands are implicit vstems
+                        19 | 20 => {
+                            self.parse_width(self.stack.len() % 2 == 1);
+                            self.stems += self.stack.len() / 2;
+                            self.stack.clear();
+                            pos += self.stems.div_ceil(8);
+                        }
+
+                        // rmoveto
+                        21 => {
+                            self.parse_width(self.stack.len() > 2);
+                            let [dx, dy] = self.args()?;
+                            self.move_to(dx, dy);
+                        }
+
+                        // hmoveto
+                        22 => {
+                            self.parse_width(self.stack.len() > 1);
+                            let [dx] = self.args()?;
+                            self.move_to(dx, 0.0);
+                        }
+
+                        // vmoveto
+                        4 => {
+                            self.parse_width(self.stack.len() > 1);
+                            let [dy] = self.args()?;
+                            self.move_to(0.0, dy);
+                        }
+
+                        // rlineto
+                        5 => {
+                            for pair in std::mem::take(&mut self.stack).chunks_exact(2) {
+                                self.line_to(pair[0], pair[1])?;
+                            }
+                        }
+
+                        // hlineto and vlineto alternate horizontal and vertical lines
+                        6 | 7 => {
+                            for (i, &d) in std::mem::take(&mut self.stack).iter().enumerate() {
+                                if (i % 2 == 0) == (op == 6) {
+                                    self.line_to(d, 0.0)?;
+                                } else {
+                                    self.line_to(0.0, d)?;
+                                }
+                            }
+                        }
+
+                        // rrcurveto
+                        8 => {
+                            for c in std::mem::take(&mut self.stack).chunks_exact(6) {
+                                self.curve_to(c[0], c[1], c[2], c[3], c[4], c[5])?;
+                            }
+                        }
+
+                        // rcurveline
+                        24 => {
+                            let args = std::mem::take(&mut self.stack);
+                            let (curves, line) = args.split_at(args.len().checked_sub(2)?);
+                            for c in curves.chunks_exact(6) {
+                                self.curve_to(c[0], c[1], c[2], c[3], c[4], c[5])?;
+                            }
+
+                            self.line_to(line[0], line[1])?;
+                        }
+
+                        // rlinecurve
+                        25 => {
+                            let args = std::mem::take(&mut self.stack);
+                            let (lines, c) = args.split_at(args.len().checked_sub(6)?);
+                            for pair in lines.chunks_exact(2) {
+                                self.line_to(pair[0], pair[1])?;
+                            }
+
+                            self.curve_to(c[0], c[1], c[2], c[3], c[4], c[5])?;
+                        }
+
+                        // vvcurveto and hhcurveto, an odd operand count starts with the perpendicular offset of the first curve
+                        26 | 27 => {
+                            let args = std::mem::take(&mut self.stack);
+                            let (mut first, curves) = if args.len() % 2 == 1 { (args[0], &args[1..]) } else { (0.0, &args[..]) };
+
+                            for c in curves.chunks_exact(4) {
+                                if op == 26 {
+                                    self.curve_to(first, c[0], c[1], c[2], 0.0, c[3])?;
+                                } else {
+                                    self.curve_to(c[0], first, c[1], c[2], c[3], 0.0)?;
+                                }
+
+                                first = 0.0;
+                            }
+                        }
+
+                        // vhcurveto and hvcurveto alternate the starting direction, the last curve can end with a fifth operand
+                        30 | 31 => {
+                            let args = std::mem::take(&mut self.stack);
+                            let mut horizontal = op == 31;
+                            let mut i = 0;
+
+                            while i + 4 <= args.len() {
+                                let c = &args[i..i + 4];
+                                let last = if args.len() - i == 5 { args[i + 4] } else { 0.0 };
+
+                                if horizontal {
+                                    self.curve_to(c[0], 0.0, c[1], c[2], last, c[3])?;
+                                } else {
+                                    self.curve_to(0.0, c[0], c[1], c[2], c[3], last)?;
+                                }
+
+                                horizontal = !horizontal;
+                                i += 4;
+                            }
+                        }
+
+                        // callsubr, callgsubr
+                        10 | 29 => {
+                            if depth >= MAX_SUBR_DEPTH {
+                                return None;
+                            }
+
+                            let subrs = if op == 10 { self.local_subrs } else { self.global_subrs };
+                            let number = self.stack.pop()? as i32 + subrs.bias();
+                            let subr = subrs.get(self.data, usize::try_from(number).ok()?)?;
+
+                            if self.run(subr, depth + 1)? {
+                                return Some(true);
+                            }
+                        }
+
+                        // return
+                        11 => return Some(false),
+
+                        // endchar, the accented characters of the deprecated `seac` form are not composed
+                        14 => {
+                            self.parse_width(self.stack.len() == 1 || self.stack.len() == 5);
+                            return Some(true);
+                        }
+
+                        // flex
+                        1235 => {
+                            let [dx1, dy1, dx2, dy2, dx3, dy3, dx4, dy4, dx5, dy5, dx6, dy6, _] = self.args()?;
+                            self.curve_to(dx1, dy1, dx2, dy2, dx3, dy3)?;
+                            self.curve_to(dx4, dy4, dx5, dy5, dx6, dy6)?;
+                        }
+
+                        // hflex
+                        1234 => {
+                            let [dx1, dx2, dy2, dx3, dx4, dx5, dx6] = self.args()?;
+                            self.curve_to(dx1, 0.0, dx2, dy2, dx3, 0.0)?;
+                            self.curve_to(dx4, 0.0, dx5, -dy2, dx6, 0.0)?;
+                        }
+
+                        // hflex1
+                        1236 => {
+                            let [dx1, dy1, dx2, dy2, dx3, dx4, dx5, dy5, dx6] = self.args()?;
+                            self.curve_to(dx1, dy1, dx2, dy2, dx3, 0.0)?;
+                            self.curve_to(dx4, 0.0, dx5, dy5, dx6, -(dy1 + dy2 + dy5))?;
+                        }
+
+                        // flex1, the last point returns to the starting level along the shorter direction
+                        1237 => {
+                            let [dx1, dy1, dx2, dy2, dx3, dy3, dx4, dy4, dx5, dy5, d6] = self.args()?;
+                            let dx = dx1 + dx2 + dx3 + dx4 + dx5;
+                            let dy = dy1 + dy2 + dy3 + dy4 + dy5;
+                            let (dx6, dy6) = if dx.abs() > dy.abs() { (d6, -dy) } else { (-dx, d6) };
+
+                            self.curve_to(dx1, dy1, dx2, dy2, dx3, dy3)?;
+                            self.curve_to(dx4, dy4, dx5, dy5, dx6, dy6)?;
+                        }
+
+                        // The arithmetic and storage operators are not supported
+                        _ => return None
+                    }
+                }
+            }
+        }
+
+        Some(false)
+    }
+
+    fn push(&mut self, value: f32) -> Option<()> {
+        if self.stack.len() >= MAX_STACK {
+            return None;
+        }
+
+        self.stack.push(value);
+        Some(())
+    }
+
+    /// Removes the advance width in front of the operands, the metrics come from the `hmtx` table instead
+    fn parse_width(&mut self, has_width: bool) {
+        if !self.width_parsed && has_width {
+            self.stack.remove(0);
+        }
+
+        self.width_parsed = true;
+    }
+
+    /// Takes the whole stack as the operands of an operator with a fixed operand count
+    fn args<const N: usize>(&mut self) -> Option<[f32; N]> {
+        std::mem::take(&mut self.stack).try_into().ok()
+    }
+
+    fn move_to(&mut self, dx: f32, dy: f32) {
+        self.x += dx;
+        self.y += dy;
+        self.contours.push(vec![OutlinePoint { x: self.x, y: self.y, on_curve: true }]);
+    }
+
+    fn line_to(&mut self, dx: f32, dy: f32) -> Option<()> {
+        self.x += dx;
+        self.y += dy;
+        self.contours.last_mut()?.push(OutlinePoint { x: self.x, y: self.y, on_curve: true });
+
+        Some(())
+    }
+
+    fn curve_to(&mut self, dx1: f32, dy1: f32, dx2: f32, dy2: f32, dx3: f32, dy3: f32) -> Option<()> {
+        let contour = self.contours.last_mut()?;
+        let (x1, y1) = (self.x + dx1, self.y + dy1);
+        let (x2, y2) = (x1 + dx2, y1 + dy2);
+        self.x = x2 + dx3;
+        self.y = y2 + dy3;
+
+        contour.push(OutlinePoint { x: x1, y: y1, on_curve: false });
+        contour.push(OutlinePoint { x: x2, y: y2, on_curve: false });
+        contour.push(OutlinePoint { x: self.x, y: self.y, on_curve: true });
+
+        Some(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+use crate::formats::ImageFormat;
+use crate::models::{Connectivity, Pixel};
+
+/// Largest color distance from the seed pixel still considered part of the region
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorTolerance {
+    /// Euclidean distance of the RGB channels (0 - 441)
+    Rgb(f32),
+    /// CIE76 delta E in Lab space, closer to perceived differences (2.3 is barely noticeable)
+    Lab(f32)
+}
+
+impl ColorTolerance {
+    pub fn accepts(&self, seed: &Pixel, other: &Pixel) -> bool {
+        match self {
+            ColorTolerance::Rgb(tolerance) => (seed.distance_squared(other) as f32).sqrt() <= *tolerance,
+            ColorTolerance::Lab(tolerance) => seed.lab_distance(other) <= *tolerance
+        }
+    }
+}
+
+/// Fills the region of similar colors connected to the seed pixel, returns the number of filled pixels
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::drawing::{flood_fill, ColorTolerance};
+/// use rusty_imager::models::{Colors, Connectivity};
+///
+/// let mut img = Image::from_file("<path>").expect("File not found!");
+/// flood_fill(&mut img, 0, 0, Colors::WHITE, ColorTolerance::Lab(10.0), Connectivity::Four);
+/// ```
+pub fn flood_fill(
+    img: &mut dyn ImageFormat,
+    x: usize,
+    y: usize,
+    color: Pixel,
+    tolerance: ColorTolerance,
+    connectivity: Connectivity
+) -> usize {
+    let selection = magic_wand(img, x, y, tolerance, connectivity);
+    let width = img.get_width();
+    let mut count = 0;
+
+    for (i, _) in selection.iter().enumerate().filter(|(_, &selected)| selected) {
+        img.set_pixel(i % width, i / width, color.clone());
+        count += 1;
+    }
+
+    count
+}
+
+/// Selects the region of similar colors connected to the seed pixel without modifying the image
+///
+/// Returns a flag for every pixel stored row by row, empty selection when the seed is outside of the image
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::drawing::{magic_wand, ColorTolerance};
+/// use rusty_imager::formats::ImageFormat;
+/// use rusty_imager::models::Connectivity;
+///
+/// let img = Image::from_file("<path>").expect("File not found!");
+/// let backdrop = magic_wand(&img, 0, 0, ColorTolerance::Rgb(40.0), Connectivity::Eight);
+///
+/// let area = backdrop.iter().filter(|&&selected| selected).count();
+/// println!("Backdrop covers {:.1}%", 100.0 * area as f32 / (img.get_width() * img.get_height()) as f32);
+/// ```
+pub fn magic_wand(
+    img: &dyn ImageFormat,
+    x: usize,
+    y: usize,
+    tolerance: ColorTolerance,
+    connectivity: Connectivity
+) -> Vec<bool> {
+    let width = img.get_width();
+    let height = img.get_height();
+    let mut selected = vec![false; width * height];
+
+    let Some(seed) = img.get_pixel(x, y).cloned() else {
+        return selected;
+    };
+
+    let matches = |x: usize, y: usize| img.get_pixel(x, y).is_some_and(|p| tolerance.accepts(&seed, p));
+    let mut stack = vec![(x, y)];
+
+    while let Some((x, y)) = stack.pop() {
+        if selected[y * width + x] || !matches(x, y) {
+            continue;
+        }
+
+        // Extends the seed to the whole horizontal run of matching pixels
+        let mut left = x;
+        while left > 0 && !selected[y * width + left - 1] && matches(left - 1, y) {
+            left -= 1;
+        }
+
+        let mut right = x;
+        while right + 1 < width && !selected[y * width + right + 1] && matches(right + 1, y) {
+            right += 1;
+        }
+
+        selected[y * width + left..=y * width + right].fill(true);
+
+        // Diagonal neighbours widen the range scanned in the adjacent rows
+        let (from, to) = match connectivity {
+            Connectivity::Four => (left, right),
+            Connectivity::Eight => (left.saturating_sub(1), (right + 1).min(width - 1))
+        };
+
+        for ny in [y.checked_sub(1), Some(y + 1).filter(|&ny| ny < height)].into_iter().flatten() {
+            // Only the first pixel of every matching run is pushed
+            let mut in_run = false;
+
+            for nx in from..=to {
+                let candidate = !selected[ny * width + nx] && matches(nx, ny);
+                if candidate && !in_run {
+                    stack.push((nx, ny));
+                }
+
+                in_run = candidate;
+            }
+        }
+    }
+
+    selected
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+use crate::formats::ImageFormat;
+use crate::models::{Pixel, Point};
+
+use super::plot::{blend, plot};
+use super::polygon::{fill_polygon, FillRule};
+
+/// Draws a one pixel wide line (Bresenham)
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::drawing::draw_line;
+/// use rusty_imager::models::{Colors, Point};
+///
+/// let mut img = Image::from_file("<path>").expect("File not found!");
+/// draw_line(&mut img, Point::new(0.0, 0.0), Point::new(100.0, 50.0), Colors::RED);
+/// ```
+pub fn draw_line(img: &mut dyn ImageFormat, start: Point, end: Point, color: Pixel) {
+    let (mut x0, mut y0) = (start.x.round() as isize, start.y.round() as isize);
+    let (x1, y1) = (end.x.round() as isize, end.y.round() as isize);
+
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx = if x0 < x1 { 1 } else { -1 };
+    let sy = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    loop {
+        plot(img, x0, y0, &color);
+
+        if x0 == x1 && y0 == y1 {
+            break;
+        }
+
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x0 += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y0 += sy;
+        }
+    }
+}
+
+/// Draws an anti-aliased line (Xiaolin Wu), the color is blended with the pixels underneath
+pub fn draw_line_aa(img: &mut dyn ImageFormat, start: Point, end: Point, color: Pixel) {
+    let (mut x0, mut y0, mut x1, mut y1) = (start.x, start.y, end.x, end.y);
+
+    let steep = (y1 - y0).abs() > (x1 - x0).abs();
+    if steep {
+        std::mem::swap(&mut x0, &mut y0);
+        std::mem::swap(&mut x1, &mut y1);
+    }
+    if x0 > x1 {
+        std::mem::swap(&mut x0, &mut x1);
+        std::mem::swap(&mut y0, &mut y1);
+    }
+
+    let gradient = if x1 - x0 == 0.0 { 1.0 } else { (y1 - y0) / (x1 - x0) };
+
+    // Swaps the coordinates back for steep lines
+    let mut put = |x: isize, y: isize, coverage: f32| {
+        if steep {
+            blend(img, y, x, &color, coverage);
+        } else {
+            blend(img, x, y, &color, coverage);
+        }
+    };
+
+    // Endpoints are weighted by how much of their pixel the line covers
+    let endpoint = |x: f32, y: f32, is_end: bool, put: &mut dyn FnMut(isize, isize, f32)| {
+        let xend = x.round();
+        let yend = y + gradient * (xend - x);
+        let xgap = if is_end { fpart(x + 0.5) } else { 1.0 - fpart(x + 0.5) };
+        let (px, py) = (xend as isize, yend.floor() as isize);
+
+        put(px, py, (1.0 - fpart(yend)) * xgap);
+        put(px, py + 1, fpart(yend) * xgap);
+
+        (px, yend + gradient)
+    };
+
+    let (first, mut intery) = endpoint(x0, y0, false, &mut put);
+    let (last, _) = endpoint(x1, y1, true, &mut put);
+
+    for x in first + 1..last {
+        let y = intery.floor() as isize;
+        put(x, y, 1.0 - fpart(intery));
+        put(x, y + 1, fpart(intery));
+        intery += gradient;
+    }
+}
+
+/// Fractional part that stays positive for negative values, unlike `f32::fract`
+fn fpart(value: f32) -> f32 {
+    value - value.floor()
+}
+
+/// Draws a line of the given width with square ends
+pub fn draw_thick_line(img: &mut dyn ImageFormat, start: Point, end: Point, thickness: f32, color: Pixel) {
+    let length = start.distance(&end);
+    if thickness <= 1.0 || length == 0.0 {
+        draw_line(img, start, end, color);
+        return;
+    }
+
+    // Offset of the edges perpendicular to the line
+    let nx = -(end.y - start.y) / length * thickness / 2.0;
+    let ny = (end.x - start.x) / length * thickness / 2.0;
+
+    let corners = [
+        Point::new(start.x + nx, start.y + ny),
+        Point::new(end.x + nx, end.y + ny),
+        Point::new(end.x - nx, end.y - ny),
+        Point::new(start.x - nx, start.y - ny)
+    ];
+
+    fill_polygon(img, &corners, FillRule::NonZero, color);
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+mod plot;
+mod line;
+mod shapes;
+mod polygon;
+mod bezier;
+mod font;
+mod truetype;
+mod cff;
+mod text;
+mod fill;
+
+pub use line::{draw_line, draw_line_aa, draw_thick_line};
+pub use shapes::{draw_circle, draw_ellipse, draw_rect, fill_circle, fill_ellipse, fill_rect};
+pub use polygon::{draw_polygon, fill_polygon, FillRule};
+pub use bezier::{draw_cubic_bezier, draw_quadratic_bezier};
+pub use font::{BitmapFont, Font, Glyph, LineMetrics};
+pub use truetype::TrueTypeFont;
+pub use text::{draw_text, measure_text, TextAlign, TextStyle};
+pub use fill::{flood_fill, magic_wand, ColorTolerance};
+
+pub(crate) use shapes::circle_offsets;
\ No newline at end of file
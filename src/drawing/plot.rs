@@ -0,0 +1,44 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+
+/// Sets the pixel, positions outside of the image are skipped
+pub(super) fn plot(img: &mut dyn ImageFormat, x: isize, y: isize, color: &Pixel) {
+    if x >= 0 && y >= 0 {
+        img.set_pixel(x as usize, y as usize, color.clone());
+    }
+}
+
+/// Mixes the color into the pixel by its coverage (0 - 1)
+pub(super) fn blend(img: &mut dyn ImageFormat, x: isize, y: isize, color: &Pixel, coverage: f32) {
+    if x < 0 || y < 0 || coverage <= 0.0 {
+        return;
+    }
+
+    let (x, y) = (x as usize, y as usize);
+    let Some(current) = img.get_pixel(x, y) else {
+        return;
+    };
+
+    let a = coverage.min(1.0);
+    let mix = |old: u8, new: u8| (old as f32 * (1.0 - a) + new as f32 * a).round() as u8;
+    let mixed = Pixel {
+        r: mix(current.r, color.r),
+        g: mix(current.g, color.g),
+        b: mix(current.b, color.b)
+    };
+
+    img.set_pixel(x, y, mixed);
+}
+
+/// Sets a horizontal run of pixels from `x0` to `x1` (inclusive), clipped to the image
+pub(super) fn span(img: &mut dyn ImageFormat, x0: isize, x1: isize, y: isize, color: &Pixel) {
+    if y < 0 || y >= img.get_height() as isize {
+        return;
+    }
+
+    let start = x0.max(0);
+    let end = x1.min(img.get_width() as isize - 1);
+    for x in start..=end {
+        img.set_pixel(x as usize, y as usize, color.clone());
+    }
+}
\ No newline at end of file
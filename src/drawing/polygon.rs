@@ -0,0 +1,89 @@
+use crate::formats::ImageFormat;
+use crate::models::{Pixel, Point};
+
+use super::line::draw_line;
+use super::plot::span;
+
+/// Decides which parts of a self intersecting polygon are inside
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// Inside when a ray from the point crosses the border an odd number of times
+    EvenOdd,
+    /// Inside when the border winds around the point, overlapping loops stay filled
+    NonZero
+}
+
+/// Draws the closed outline of the polygon
+pub fn draw_polygon(img: &mut dyn ImageFormat, points: &[Point], color: Pixel) {
+    for (i, point) in points.iter().enumerate() {
+        draw_line(img, *point, points[(i + 1) % points.len()], color.clone());
+    }
+}
+
+/// Fills the polygon with a scanline algorithm
+///
+/// Pixels whose centers lie inside the polygon are filled, vertices use pixel coordinates
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::drawing::{fill_polygon, FillRule};
+/// use rusty_imager::models::{Colors, Point};
+///
+/// let mut img = Image::from_file("<path>").expect("File not found!");
+/// let star = [
+///     Point::new(50.0, 0.0), Point::new(80.0, 90.0), Point::new(5.0, 35.0),
+///     Point::new(95.0, 35.0), Point::new(20.0, 90.0)
+/// ];
+///
+/// fill_polygon(&mut img, &star, FillRule::NonZero, Colors::GOLD);
+/// ```
+pub fn fill_polygon(img: &mut dyn ImageFormat, points: &[Point], rule: FillRule, color: Pixel) {
+    if points.len() < 3 {
+        return;
+    }
+
+    let min_y = points.iter().map(|p| p.y).fold(f32::INFINITY, f32::min).ceil().max(0.0) as isize;
+    let max_y = points.iter().map(|p| p.y).fold(f32::NEG_INFINITY, f32::max).min(img.get_height() as f32);
+
+    let mut crossings: Vec<(f32, i32)> = vec![];
+
+    let mut y = min_y;
+    while (y as f32) < max_y {
+        let sample = y as f32;
+        crossings.clear();
+
+        for (i, a) in points.iter().enumerate() {
+            let b = &points[(i + 1) % points.len()];
+
+            // Half open so a vertex shared by two edges is counted once
+            let (low, high) = if a.y < b.y { (a, b) } else { (b, a) };
+            if sample < low.y || sample >= high.y {
+                continue;
+            }
+
+            let x = a.x + (sample - a.y) * (b.x - a.x) / (b.y - a.y);
+            crossings.push((x, if b.y > a.y { 1 } else { -1 }));
+        }
+
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut winding = 0;
+        for (i, &(x, direction)) in crossings.iter().enumerate() {
+            let inside = match rule {
+                FillRule::EvenOdd => i % 2 == 0,
+                FillRule::NonZero => {
+                    winding += direction;
+                    winding != 0
+                }
+            };
+
+            if inside {
+                if let Some(&(next, _)) = crossings.get(i + 1) {
+                    span(img, x.ceil() as isize, next.ceil() as isize - 1, y, &color);
+                }
+            }
+        }
+
+        y += 1;
+    }
+}
\ No newline at end of file
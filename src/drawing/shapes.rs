@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use crate::formats::ImageFormat;
+use crate::models::{Pixel, Point, Rect};
+
+use super::plot::{plot, span};
+
+/// Draws the outline of the rectangle
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::drawing::draw_rect;
+/// use rusty_imager::models::{Colors, Rect};
+///
+/// let mut img = Image::from_file("<path>").expect("File not found!");
+/// draw_rect(&mut img, Rect::new(10, 10, 50, 20), Colors::LIME);
+/// ```
+pub fn draw_rect(img: &mut dyn ImageFormat, rect: Rect, color: Pixel) {
+    if rect.area() == 0 {
+        return;
+    }
+
+    let (left, top) = (rect.x as isize, rect.y as isize);
+    let right = left + rect.width as isize - 1;
+    let bottom = top + rect.height as isize - 1;
+
+    span(img, left, right, top, &color);
+    span(img, left, right, bottom, &color);
+    for y in top..=bottom {
+        plot(img, left, y, &color);
+        plot(img, right, y, &color);
+    }
+}
+
+/// Fills the rectangle
+pub fn fill_rect(img: &mut dyn ImageFormat, rect: Rect, color: Pixel) {
+    if rect.area() == 0 {
+        return;
+    }
+
+    for y in rect.y..rect.y + rect.height {
+        span(img, rect.x as isize, (rect.x + rect.width) as isize - 1, y as isize, &color);
+    }
+}
+
+/// Draws the outline of the circle (midpoint circle algorithm)
+pub fn draw_circle(img: &mut dyn ImageFormat, center: Point, radius: f32, color: Pixel) {
+    let (cx, cy) = (center.x.round() as isize, center.y.round() as isize);
+
+    for (dx, dy) in circle_offsets(radius.round() as usize) {
+        plot(img, cx + dx, cy + dy, &color);
+    }
+}
+
+/// Fills the circle, the filled area matches the outline of `draw_circle`
+pub fn fill_circle(img: &mut dyn ImageFormat, center: Point, radius: f32, color: Pixel) {
+    let (cx, cy) = (center.x.round() as isize, center.y.round() as isize);
+
+    for (dy, dx) in row_extents(circle_offsets(radius.round() as usize)) {
+        span(img, cx - dx, cx + dx, cy + dy, &color);
+    }
+}
+
+/// Draws the outline of the axis aligned ellipse (midpoint ellipse algorithm)
+/// * `radius_x` - horizontal semi-axis
+/// * `radius_y` - vertical semi-axis
+pub fn draw_ellipse(img: &mut dyn ImageFormat, center: Point, radius_x: f32, radius_y: f32, color: Pixel) {
+    let (cx, cy) = (center.x.round() as isize, center.y.round() as isize);
+
+    for (dx, dy) in ellipse_offsets(radius_x.round() as usize, radius_y.round() as usize) {
+        plot(img, cx + dx, cy + dy, &color);
+    }
+}
+
+/// Fills the axis aligned ellipse, the filled area matches the outline of `draw_ellipse`
+pub fn fill_ellipse(img: &mut dyn ImageFormat, center: Point, radius_x: f32, radius_y: f32, color: Pixel) {
+    let (cx, cy) = (center.x.round() as isize, center.y.round() as isize);
+
+    for (dy, dx) in row_extents(ellipse_offsets(radius_x.round() as usize, radius_y.round() as usize)) {
+        span(img, cx - dx, cx + dx, cy + dy, &color);
+    }
+}
+
+/// Pixel offsets of a midpoint circle outline
+pub(crate) fn circle_offsets(radius: usize) -> Vec<(isize, isize)> {
+    let mut offsets = vec![];
+    let mut x = radius as isize;
+    let mut y = 0isize;
+    let mut err = 1 - x;
+
+    while x >= y {
+        for (dx, dy) in [(x, y), (y, x), (-y, x), (-x, y), (-x, -y), (-y, -x), (y, -x), (x, -y)] {
+            offsets.push((dx, dy));
+        }
+
+        y += 1;
+        if err < 0 {
+            err += 2 * y + 1;
+        } else {
+            x -= 1;
+            err += 2 * (y - x) + 1;
+        }
+    }
+
+    offsets.sort();
+    offsets.dedup();
+    offsets
+}
+
+/// Pixel offsets of a midpoint ellipse outline
+fn ellipse_offsets(radius_x: usize, radius_y: usize) -> Vec<(isize, isize)> {
+    let (a2, b2) = ((radius_x * radius_x) as f64, (radius_y * radius_y) as f64);
+    let mut quadrant = vec![];
+    let mut x = 0isize;
+    let mut y = radius_y as isize;
+
+    // Region where the slope is flatter than -1, steps along x
+    let mut dx = 0f64;
+    let mut dy = 2.0 * a2 * y as f64;
+    let mut d = b2 - a2 * radius_y as f64 + a2 / 4.0;
+    while dx < dy {
+        quadrant.push((x, y));
+        x += 1;
+        dx += 2.0 * b2;
+
+        if d < 0.0 {
+            d += dx + b2;
+        } else {
+            y -= 1;
+            dy -= 2.0 * a2;
+            d += dx - dy + b2;
+        }
+    }
+
+    // Steep region, steps along y
+    let mut d = b2 * (x as f64 + 0.5).powi(2) + a2 * (y as f64 - 1.0).powi(2) - a2 * b2;
+    while y >= 0 {
+        quadrant.push((x, y));
+        y -= 1;
+        dy -= 2.0 * a2;
+
+        if d > 0.0 {
+            d += a2 - dy;
+        } else {
+            x += 1;
+            dx += 2.0 * b2;
+            d += dx - dy + a2;
+        }
+    }
+
+    let mut offsets: Vec<(isize, isize)> = quadrant.iter()
+        .flat_map(|&(x, y)| [(x, y), (-x, y), (x, -y), (-x, -y)])
+        .collect();
+
+    offsets.sort();
+    offsets.dedup();
+    offsets
+}
+
+/// Widest horizontal offset of the outline on every row
+fn row_extents(offsets: Vec<(isize, isize)>) -> BTreeMap<isize, isize> {
+    let mut rows = BTreeMap::new();
+    for (dx, dy) in offsets {
+        let extent = rows.entry(dy).or_insert(0);
+        *extent = dx.abs().max(*extent);
+    }
+
+    rows
+}
\ No newline at end of file
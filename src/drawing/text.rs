@@ -0,0 +1,92 @@
+use crate::formats::ImageFormat;
+use crate::models::{Pixel, Point};
+
+use super::font::Font;
+use super::plot::blend;
+
+/// Horizontal placement of the lines relative to the text position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextAlign {
+    /// Lines start at the position
+    Left,
+    /// Lines are centered on the position
+    Center,
+    /// Lines end at the position
+    Right
+}
+
+/// Appearance of drawn text
+#[derive(Debug, Clone)]
+pub struct TextStyle {
+    /// Height of the em square in pixels
+    pub size: f32,
+    pub color: Pixel,
+    pub align: TextAlign
+}
+
+impl TextStyle {
+    /// Left aligned text of the given size and color
+    pub fn new(size: f32, color: Pixel) -> Self {
+        Self { size, color, align: TextAlign::Left }
+    }
+
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+}
+
+/// Width and height of the text box, lines are separated by `\n`
+pub fn measure_text(text: &str, font: &dyn Font, size: f32) -> (f32, f32) {
+    let metrics = font.line_metrics(size);
+    let lines = text.split('\n').count();
+    let width = text.split('\n').map(|line| line_width(line, font, size)).fold(0.0, f32::max);
+
+    (width, lines as f32 * metrics.line_height() - metrics.line_gap)
+}
+
+/// Draws anti-aliased text, lines are separated by `\n`
+/// * `position` - top of the text box, horizontally the anchor of the alignment
+/// # Example
+/// ```no_run
+/// use rusty_imager::Image;
+/// use rusty_imager::drawing::{draw_text, BitmapFont, TextAlign, TextStyle};
+/// use rusty_imager::models::{Colors, Point};
+///
+/// let mut img = Image::from_file("<path>").expect("File not found!");
+/// let style = TextStyle::new(16.0, Colors::WHITE).with_align(TextAlign::Center);
+///
+/// draw_text(&mut img, "Hello\nworld", Point::new(100.0, 10.0), &BitmapFont::new(), &style);
+/// ```
+pub fn draw_text(img: &mut dyn ImageFormat, text: &str, position: Point, font: &dyn Font, style: &TextStyle) {
+    let metrics = font.line_metrics(style.size);
+    let mut baseline = position.y + metrics.ascent;
+
+    for line in text.split('\n') {
+        let mut pen = match style.align {
+            TextAlign::Left => position.x,
+            TextAlign::Center => position.x - line_width(line, font, style.size) / 2.0,
+            TextAlign::Right => position.x - line_width(line, font, style.size)
+        };
+
+        for ch in line.chars() {
+            let glyph = font.glyph(ch, style.size);
+            let left = pen.round() as isize + glyph.left as isize;
+            let top = baseline.round() as isize + glyph.top as isize;
+
+            for (i, &coverage) in glyph.coverage.iter().enumerate() {
+                let x = left + (i % glyph.width) as isize;
+                let y = top + (i / glyph.width) as isize;
+                blend(img, x, y, &style.color, coverage);
+            }
+
+            pen += glyph.advance;
+        }
+
+        baseline += metrics.line_height();
+    }
+}
+
+fn line_width(line: &str, font: &dyn Font, size: f32) -> f32 {
+    line.chars().map(|ch| font.advance(ch, size)).sum()
+}
\ No newline at end of file
@@ -0,0 +1,659 @@
+use std::fs;
+
+use super::cff::Cff;
+use super::font::{Font, Glyph, LineMetrics};
+
+/// Nesting limit of composite glyphs, protects against cycles in broken fonts
+const MAX_COMPOSITE_DEPTH: usize = 8;
+
+/// Limit of glyph outlines loaded for one character, protects against exponentially nested composites
+const MAX_OUTLINE_LOADS: usize = 256;
+
+/// Largest glyph bitmap in square ems, bigger ones only come from broken outlines
+const MAX_GLYPH_AREA: f32 = 16.0;
+
+/// Outline point in font units
+#[derive(Debug, Clone, Copy)]
+pub(super) struct OutlinePoint {
+    pub(super) x: f32,
+    pub(super) y: f32,
+    pub(super) on_curve: bool
+}
+
+/// Source of the glyph outlines
+enum Outlines {
+    /// Quadratic TrueType outlines of the `glyf` table, located through the `loca` table
+    Glyf { glyf: usize, loca: usize, long_offsets: bool },
+    /// Cubic PostScript outlines of the `CFF ` table
+    Cff(Cff)
+}
+
+/// TrueType or OpenType font rendered with anti-aliasing
+///
+/// Supports Unicode `cmap` format 4 (the basic multilingual plane), horizontal metrics, simple and composite `glyf` outlines and `CFF ` outlines including CID-keyed fonts.
+/// Hinting and kerning are ignored, variable fonts (`CFF2`) are not supported
+pub struct TrueTypeFont {
+    data: Vec<u8>,
+    units_per_em: f32,
+    /// Bounding box of all glyphs (x min, y min, x max, y max), `None` when the font leaves it empty
+    bounds: Option<[f32; 4]>,
+    outlines: Outlines,
+    num_glyphs: u16,
+    hmtx: usize,
+    num_h_metrics: u16,
+    cmap: usize,
+    ascender: i16,
+    descender: i16,
+    line_gap: i16
+}
+
+impl TrueTypeFont {
+    /// Loads the font from specified path
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::drawing::{draw_text, TextStyle, TrueTypeFont};
+    /// use rusty_imager::models::{Colors, Point};
+    ///
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// let font = TrueTypeFont::from_file("<path>.ttf").expect("Invalid font");
+    ///
+    /// draw_text(&mut img, "Hello", Point::new(10.0, 10.0), &font, &TextStyle::new(32.0, Colors::WHITE));
+    /// ```
+    pub fn from_file(path: &str) -> Option<Self> {
+        Self::parse(fs::read(path).ok()?)
+    }
+
+    /// Parses the font from the content of a `.ttf` or `.otf` file
+    ///
+    /// Returns `None` for malformed fonts
+    pub fn parse(data: Vec<u8>) -> Option<Self> {
+        let num_tables = read_u16(&data, 4)? as usize;
+        let mut tables = std::collections::HashMap::new();
+
+        for i in 0..num_tables {
+            let record = 12 + i * 16;
+            let tag = data.get(record..record + 4)?;
+            tables.insert(tag.to_vec(), read_u32(&data, record + 8)? as usize);
+        }
+
+        let table = |tag: &[u8]| tables.get(tag).copied();
+        let head = table(b"head")?;
+        let hhea = table(b"hhea")?;
+        let maxp = table(b"maxp")?;
+
+        let units_per_em = read_u16(&data, head + 18)?;
+        if !(16..=16384).contains(&units_per_em) {
+            return None;
+        }
+
+        let bounds = [36, 38, 40, 42].map(|offset| read_i16(&data, head + offset).map(|v| v as f32));
+        let bounds = match bounds {
+            [Some(x_min), Some(y_min), Some(x_max), Some(y_max)] if x_min < x_max && y_min < y_max => Some([x_min, y_min, x_max, y_max]),
+            _ => None
+        };
+
+        let mut num_glyphs = read_u16(&data, maxp + 4)?;
+        let outlines = match table(b"glyf") {
+            Some(glyf) => Outlines::Glyf { glyf, loca: table(b"loca")?, long_offsets: read_i16(&data, head + 50)? != 0 },
+
+            None => {
+                let cff = Cff::parse(&data, table(b"CFF ")?)?;
+                num_glyphs = num_glyphs.min(cff.glyph_count() as u16);
+                Outlines::Cff(cff)
+            }
+        };
+
+        let font = Self {
+            units_per_em: units_per_em as f32,
+            bounds,
+            outlines,
+            num_glyphs,
+            hmtx: table(b"hmtx")?,
+            num_h_metrics: read_u16(&data, hhea + 34)?,
+            cmap: find_unicode_cmap(&data, table(b"cmap")?)?,
+            ascender: read_i16(&data, hhea + 4)?,
+            descender: read_i16(&data, hhea + 6)?,
+            line_gap: read_i16(&data, hhea + 8)?,
+            data
+        };
+
+        if font.num_h_metrics == 0 {
+            return None;
+        }
+
+        Some(font)
+    }
+
+    /// Index of the glyph of the character, 0 (the missing glyph) when the font does not cover it
+    pub fn glyph_index(&self, ch: char) -> u16 {
+        self.lookup(ch as u32).unwrap_or(0)
+    }
+
+    fn lookup(&self, code: u32) -> Option<u16> {
+        if code > 0xFFFF {
+            return None;
+        }
+
+        let data = &self.data;
+        let seg_count_x2 = read_u16(data, self.cmap + 6)? as usize;
+        let ends = self.cmap + 14;
+        let starts = ends + seg_count_x2 + 2;
+        let deltas = starts + seg_count_x2;
+        let range_offsets = deltas + seg_count_x2;
+
+        for i in (0..seg_count_x2).step_by(2) {
+            if code > read_u16(data, ends + i)? as u32 {
+                continue;
+            }
+
+            let start = read_u16(data, starts + i)? as u32;
+            if code < start {
+                return None;
+            }
+
+            let delta = read_u16(data, deltas + i)?;
+            let range_offset = read_u16(data, range_offsets + i)? as usize;
+            if range_offset == 0 {
+                return Some((code as u16).wrapping_add(delta));
+            }
+
+            // The offset is relative to its own position in the table
+            let glyph = read_u16(data, range_offsets + i + range_offset + 2 * (code - start) as usize)?;
+            return if glyph == 0 { None } else { Some(glyph.wrapping_add(delta)) };
+        }
+
+        None
+    }
+
+    fn advance_width(&self, glyph: u16) -> f32 {
+        let metric = glyph.min(self.num_h_metrics - 1) as usize;
+
+        read_u16(&self.data, self.hmtx + metric * 4).unwrap_or(0) as f32
+    }
+
+    fn glyph_range(&self, glyph: u16) -> Option<(usize, usize)> {
+        let Outlines::Glyf { glyf, loca, long_offsets } = self.outlines else {
+            return None;
+        };
+
+        if glyph >= self.num_glyphs {
+            return None;
+        }
+
+        let g = glyph as usize;
+        let (start, end) = if long_offsets {
+            (read_u32(&self.data, loca + g * 4)? as usize, read_u32(&self.data, loca + g * 4 + 4)? as usize)
+        } else {
+            (read_u16(&self.data, loca + g * 2)? as usize * 2, read_u16(&self.data, loca + g * 2 + 2)? as usize * 2)
+        };
+
+        Some((glyf + start, glyf + end))
+    }
+
+    /// Contours of the glyph in font units
+    fn outline(&self, glyph: u16, depth: usize, budget: &mut usize) -> Option<Vec<Vec<OutlinePoint>>> {
+        *budget = budget.checked_sub(1)?;
+
+        let (start, end) = self.glyph_range(glyph)?;
+        if start >= end {
+            // Glyphs without outline like the space
+            return Some(vec![]);
+        }
+
+        let contours = read_i16(&self.data, start)?;
+        if contours >= 0 {
+            self.simple_outline(start, contours as usize)
+        } else if depth < MAX_COMPOSITE_DEPTH {
+            self.composite_outline(start, depth, budget)
+        } else {
+            None
+        }
+    }
+
+    fn simple_outline(&self, start: usize, contours: usize) -> Option<Vec<Vec<OutlinePoint>>> {
+        let data = &self.data;
+        let mut ends = Vec::with_capacity(contours);
+        for i in 0..contours {
+            ends.push(read_u16(data, start + 10 + i * 2)? as usize);
+        }
+
+        let count = ends.last().map_or(0, |e| e + 1);
+        let instructions = read_u16(data, start + 10 + contours * 2)? as usize;
+        let mut pos = start + 12 + contours * 2 + instructions;
+
+        let mut flags = Vec::with_capacity(count);
+        while flags.len() < count {
+            let flag = *data.get(pos)?;
+            pos += 1;
+            flags.push(flag);
+
+            if flag & 8 != 0 {
+                let repeat = *data.get(pos)?;
+                pos += 1;
+                flags.extend(std::iter::repeat_n(flag, repeat as usize));
+            }
+        }
+        flags.truncate(count);
+
+        // Coordinates are deltas, either a byte with the sign in the flags or a signed word
+        let mut read_coordinates = |short: u8, same: u8| -> Option<Vec<f32>> {
+            let mut value = 0i32;
+            let mut res = Vec::with_capacity(count);
+
+            for flag in &flags {
+                if flag & short != 0 {
+                    let delta = *data.get(pos)? as i32;
+                    pos += 1;
+                    value += if flag & same != 0 { delta } else { -delta };
+                } else if flag & same == 0 {
+                    value += read_i16(data, pos)? as i32;
+                    pos += 2;
+                }
+
+                res.push(value as f32);
+            }
+
+            Some(res)
+        };
+
+        let xs = read_coordinates(0x02, 0x10)?;
+        let ys = read_coordinates(0x04, 0x20)?;
+
+        let mut res = vec![];
+        let mut first = 0;
+        for end in ends {
+            if end < first || end >= count {
+                return None;
+            }
+
+            res.push((first..=end).map(|i| OutlinePoint { x: xs[i], y: ys[i], on_curve: flags[i] & 1 != 0 }).collect());
+            first = end + 1;
+        }
+
+        Some(res)
+    }
+
+    fn composite_outline(&self, start: usize, depth: usize, budget: &mut usize) -> Option<Vec<Vec<OutlinePoint>>> {
+        let data = &self.data;
+        let f2dot14 = |pos: usize| read_i16(data, pos).map(|v| v as f32 / 16384.0);
+        let mut pos = start + 10;
+        let mut res = vec![];
+
+        loop {
+            let flags = read_u16(data, pos)?;
+            let component = read_u16(data, pos + 2)?;
+            pos += 4;
+
+            let (dx, dy) = if flags & 0x01 != 0 {
+                pos += 4;
+                (read_i16(data, pos - 4)? as f32, read_i16(data, pos - 2)? as f32)
+            } else {
+                pos += 2;
+                (*data.get(pos - 2)? as i8 as f32, *data.get(pos - 1)? as i8 as f32)
+            };
+
+            // Anchor point matching is not supported, such components are placed without offset
+            let (dx, dy) = if flags & 0x02 != 0 { (dx, dy) } else { (0.0, 0.0) };
+
+            let (a, b, c, d) = if flags & 0x08 != 0 {
+                pos += 2;
+                let s = f2dot14(pos - 2)?;
+                (s, 0.0, 0.0, s)
+            } else if flags & 0x40 != 0 {
+                pos += 4;
+                (f2dot14(pos - 4)?, 0.0, 0.0, f2dot14(pos - 2)?)
+            } else if flags & 0x80 != 0 {
+                pos += 8;
+                (f2dot14(pos - 8)?, f2dot14(pos - 6)?, f2dot14(pos - 4)?, f2dot14(pos - 2)?)
+            } else {
+                (1.0, 0.0, 0.0, 1.0)
+            };
+
+            for contour in self.outline(component, depth + 1, budget)? {
+                res.push(contour.iter().map(|p| OutlinePoint {
+                    x: a * p.x + c * p.y + dx,
+                    y: b * p.x + d * p.y + dy,
+                    on_curve: p.on_curve
+                }).collect());
+            }
+
+            if flags & 0x20 == 0 {
+                break;
+            }
+        }
+
+        Some(res)
+    }
+}
+
+impl Font for TrueTypeFont {
+    fn glyph(&self, ch: char, size: f32) -> Glyph {
+        let index = self.glyph_index(ch);
+        let scale = size / self.units_per_em;
+        let advance = self.advance_width(index) * scale;
+        let empty = Glyph { width: 0, height: 0, left: 0, top: 0, advance, coverage: vec![] };
+
+        let mut budget = MAX_OUTLINE_LOADS;
+        let contours = match &self.outlines {
+            Outlines::Glyf { .. } => self.outline(index, 0, &mut budget),
+            Outlines::Cff(cff) if index < self.num_glyphs => cff.outline(&self.data, index),
+            Outlines::Cff(_) => None
+        };
+
+        let Some(mut contours) = contours else {
+            return empty;
+        };
+
+        // Points outside of the font bounding box only come from broken outlines
+        if let Some([x_min, y_min, x_max, y_max]) = self.bounds {
+            for point in contours.iter_mut().flatten() {
+                point.x = point.x.clamp(x_min, x_max);
+                point.y = point.y.clamp(y_min, y_max);
+            }
+        }
+
+        // Flattened to lines in pixel space with y pointing down
+        let flatten = match self.outlines {
+            Outlines::Glyf { .. } => flatten_contour,
+            Outlines::Cff(_) => flatten_cubic_contour
+        };
+
+        let mut lines = vec![];
+        for contour in &contours {
+            flatten(contour, scale, &mut lines);
+        }
+
+        if lines.is_empty() {
+            return empty;
+        }
+
+        let min_x = lines.iter().flat_map(|l| [l.0.0, l.1.0]).fold(f32::INFINITY, f32::min).floor();
+        let min_y = lines.iter().flat_map(|l| [l.0.1, l.1.1]).fold(f32::INFINITY, f32::min).floor();
+        let max_x = lines.iter().flat_map(|l| [l.0.0, l.1.0]).fold(f32::NEG_INFINITY, f32::max).ceil();
+        let max_y = lines.iter().flat_map(|l| [l.0.1, l.1.1]).fold(f32::NEG_INFINITY, f32::max).ceil();
+
+        if (max_x - min_x + 1.0) * (max_y - min_y + 1.0) > MAX_GLYPH_AREA * (size + 2.0).powi(2) {
+            return empty;
+        }
+
+        let width = (max_x - min_x) as usize + 1;
+        let height = (max_y - min_y) as usize + 1;
+        let mut raster = Raster::new(width, height);
+
+        for ((x0, y0), (x1, y1)) in lines {
+            raster.line((x0 - min_x, y0 - min_y), (x1 - min_x, y1 - min_y));
+        }
+
+        Glyph {
+            width,
+            height,
+            left: min_x as i32,
+            top: min_y as i32,
+            advance,
+            coverage: raster.coverage()
+        }
+    }
+
+    fn line_metrics(&self, size: f32) -> LineMetrics {
+        let scale = size / self.units_per_em;
+
+        LineMetrics {
+            ascent: self.ascender as f32 * scale,
+            descent: -self.descender as f32 * scale,
+            line_gap: self.line_gap as f32 * scale
+        }
+    }
+
+    fn advance(&self, ch: char, size: f32) -> f32 {
+        self.advance_width(self.glyph_index(ch)) * size / self.units_per_em
+    }
+}
+
+type Line = ((f32, f32), (f32, f32));
+
+/// Converts a closed contour of on and off curve points into line segments
+fn flatten_contour(contour: &[OutlinePoint], scale: f32, lines: &mut Vec<Line>) {
+    let n = contour.len();
+    if n < 2 {
+        return;
+    }
+
+    // Two consecutive off curve points imply an on curve point between them
+    let mut points: Vec<OutlinePoint> = Vec::with_capacity(n * 2);
+    for (i, point) in contour.iter().enumerate() {
+        points.push(*point);
+
+        let next = contour[(i + 1) % n];
+        if !point.on_curve && !next.on_curve {
+            points.push(OutlinePoint { x: (point.x + next.x) / 2.0, y: (point.y + next.y) / 2.0, on_curve: true });
+        }
+    }
+
+    let Some(first) = points.iter().position(|p| p.on_curve) else {
+        return;
+    };
+    points.rotate_left(first);
+
+    let to_pixels = |p: &OutlinePoint| (p.x * scale, -p.y * scale);
+    let start = to_pixels(&points[0]);
+    let mut current = start;
+    let mut i = 1;
+
+    while i <= points.len() {
+        let point = &points[i % points.len()];
+
+        if point.on_curve {
+            let next = to_pixels(point);
+            lines.push((current, next));
+            current = next;
+            i += 1;
+        } else {
+            let control = to_pixels(point);
+            let end = to_pixels(&points[(i + 1) % points.len()]);
+            flatten_quadratic(current, control, end, lines);
+            current = end;
+            i += 2;
+        }
+    }
+
+    if current != start {
+        lines.push((current, start));
+    }
+}
+
+/// Converts a closed contour of on curve points and pairs of cubic control points into line segments
+fn flatten_cubic_contour(contour: &[OutlinePoint], scale: f32, lines: &mut Vec<Line>) {
+    let to_pixels = |p: &OutlinePoint| (p.x * scale, -p.y * scale);
+    let Some(start) = contour.first().map(to_pixels) else {
+        return;
+    };
+
+    let mut current = start;
+    let mut i = 1;
+
+    while i < contour.len() {
+        if contour[i].on_curve {
+            let next = to_pixels(&contour[i]);
+            lines.push((current, next));
+            current = next;
+            i += 1;
+        } else if i + 2 < contour.len() && !contour[i + 1].on_curve {
+            let end = to_pixels(&contour[i + 2]);
+            flatten_cubic(current, to_pixels(&contour[i]), to_pixels(&contour[i + 1]), end, lines);
+            current = end;
+            i += 3;
+        } else {
+            break;
+        }
+    }
+
+    if current != start {
+        lines.push((current, start));
+    }
+}
+
+fn flatten_quadratic(start: (f32, f32), control: (f32, f32), end: (f32, f32), lines: &mut Vec<Line>) {
+    // Subdivision count grows with the square root of the curve's deviation from a straight line
+    let deviation = (start.0 - 2.0 * control.0 + end.0).hypot(start.1 - 2.0 * control.1 + end.1);
+    let segments = 1 + (3.0 * deviation).sqrt().sqrt().floor() as usize;
+
+    let mut previous = start;
+    for i in 1..=segments {
+        let t = i as f32 / segments as f32;
+        let u = 1.0 - t;
+        let point = (
+            u * u * start.0 + 2.0 * u * t * control.0 + t * t * end.0,
+            u * u * start.1 + 2.0 * u * t * control.1 + t * t * end.1
+        );
+
+        lines.push((previous, point));
+        previous = point;
+    }
+}
+
+fn flatten_cubic(start: (f32, f32), control1: (f32, f32), control2: (f32, f32), end: (f32, f32), lines: &mut Vec<Line>) {
+    // Keeps the distance between the curve and the segments below a sixteenth of a pixel
+    let deviation = (start.0 - 2.0 * control1.0 + control2.0).hypot(start.1 - 2.0 * control1.1 + control2.1)
+        .max((control1.0 - 2.0 * control2.0 + end.0).hypot(control1.1 - 2.0 * control2.1 + end.1));
+    let segments = 1 + (12.0 * deviation).sqrt().floor() as usize;
+
+    let mut previous = start;
+    for i in 1..=segments {
+        let t = i as f32 / segments as f32;
+        let u = 1.0 - t;
+        let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+        let point = (
+            a * start.0 + b * control1.0 + c * control2.0 + d * end.0,
+            a * start.1 + b * control1.1 + c * control2.1 + d * end.1
+        );
+
+        lines.push((previous, point));
+        previous = point;
+    }
+}
+
+/// Accumulates the signed area covered by the outline in every pixel
+struct Raster {
+    width: usize,
+    height: usize,
+    acc: Vec<f32>
+}
+
+impl Raster {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            acc: vec![0f32; width * height + width + 2]
+        }
+    }
+
+    fn line(&mut self, p0: (f32, f32), p1: (f32, f32)) {
+        if (p0.1 - p1.1).abs() <= f32::EPSILON {
+            return;
+        }
+
+        let (direction, p0, p1) = if p0.1 < p1.1 { (1.0, p0, p1) } else { (-1.0, p1, p0) };
+        let dxdy = (p1.0 - p0.0) / (p1.1 - p0.1);
+        let mut x = p0.0;
+
+        if p0.1 < 0.0 {
+            x -= p0.1 * dxdy;
+        }
+
+        for y in p0.1.max(0.0) as usize..self.height.min(p1.1.ceil() as usize) {
+            let row = y * self.width;
+            let dy = ((y + 1) as f32).min(p1.1) - (y as f32).max(p0.1);
+            let next_x = x + dxdy * dy;
+            let d = dy * direction;
+
+            let (x0, x1) = if x < next_x { (x, next_x) } else { (next_x, x) };
+            let x0_floor = x0.floor();
+            let x0i = x0_floor as usize;
+            let x1_ceil = x1.ceil();
+            let x1i = x1_ceil as usize;
+
+            if x1i <= x0i + 1 {
+                // The segment stays within one pixel column
+                let middle = 0.5 * (x + next_x) - x0_floor;
+                self.acc[row + x0i] += d - d * middle;
+                self.acc[row + x0i + 1] += d * middle;
+            } else {
+                let s = (x1 - x0).recip();
+                let x0f = x0 - x0_floor;
+                let a0 = 0.5 * s * (1.0 - x0f) * (1.0 - x0f);
+                let x1f = x1 - x1_ceil + 1.0;
+                let am = 0.5 * s * x1f * x1f;
+
+                self.acc[row + x0i] += d * a0;
+
+                if x1i == x0i + 2 {
+                    self.acc[row + x0i + 1] += d * (1.0 - a0 - am);
+                } else {
+                    let a1 = s * (1.5 - x0f);
+                    self.acc[row + x0i + 1] += d * (a1 - a0);
+
+                    for xi in x0i + 2..x1i - 1 {
+                        self.acc[row + xi] += d * s;
+                    }
+
+                    let a2 = a1 + (x1i - x0i - 3) as f32 * s;
+                    self.acc[row + x1i - 1] += d * (1.0 - a2 - am);
+                }
+
+                self.acc[row + x1i] += d * am;
+            }
+
+            x = next_x;
+        }
+    }
+
+    /// Coverage of every pixel, overlapping contours saturate
+    fn coverage(&self) -> Vec<f32> {
+        let mut sum = 0f32;
+
+        self.acc[..self.width * self.height].iter().map(|a| {
+            sum += a;
+            sum.abs().min(1.0)
+        }).collect()
+    }
+}
+
+/// Offset of a Unicode `cmap` subtable in format 4
+fn find_unicode_cmap(data: &[u8], cmap: usize) -> Option<usize> {
+    let count = read_u16(data, cmap + 2)? as usize;
+    let mut fallback = None;
+
+    for i in 0..count {
+        let record = cmap + 4 + i * 8;
+        let platform = read_u16(data, record)?;
+        let encoding = read_u16(data, record + 2)?;
+        let subtable = cmap + read_u32(data, record + 4)? as usize;
+
+        if read_u16(data, subtable)? != 4 {
+            continue;
+        }
+
+        // Windows Unicode BMP is preferred over the Unicode platform
+        match (platform, encoding) {
+            (3, 1) => return Some(subtable),
+            (0, _) => fallback = fallback.or(Some(subtable)),
+            _ => {}
+        }
+    }
+
+    fallback
+}
+
+pub(super) fn read_u16(data: &[u8], pos: usize) -> Option<u16> {
+    Some(u16::from_be_bytes([*data.get(pos)?, *data.get(pos + 1)?]))
+}
+
+fn read_i16(data: &[u8], pos: usize) -> Option<i16> {
+    read_u16(data, pos).map(|v| v as i16)
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(pos..pos + 4)?.try_into().ok()?))
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+use crate::formats::ImageFormat;
+use crate::models::Colors;
+use crate::utils::{calculate_gaussian_kernel_1d, luminance_plane, separable_convolution};
+
+use super::ImageFilter;
+
+/// How the local cutoff is calculated from the neighbourhood of a pixel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdaptiveMethod {
+    /// Mean of the block
+    Mean,
+    /// Gaussian weighted mean of the block
+    Gaussian,
+    /// `mean + k * std_dev`, `k` is usually -0.2
+    Niblack { k: f32 },
+    /// `mean * (1 + k * (std_dev / r - 1))`, usually `k` = 0.5 and `r` = 128
+    Sauvola { k: f32, r: f32 }
+}
+
+/// Binarizes the image with a cutoff calculated separately for every pixel, which handles uneven lighting
+pub struct AdaptiveThreshold {
+    method: AdaptiveMethod,
+    block_size: usize,
+    offset: f32
+}
+
+impl AdaptiveThreshold {
+    /// * `method` - how the local cutoff is calculated
+    /// * `block_size` - size of the neighbourhood, must be odd
+    /// * `offset` - constant subtracted from the local cutoff
+    /// 
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::{AdaptiveMethod, AdaptiveThreshold};
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(AdaptiveThreshold::new(AdaptiveMethod::Sauvola { k: 0.5, r: 128.0 }, 25, 0.0));
+    /// ```
+    pub fn new(method: AdaptiveMethod, block_size: usize, offset: f32) -> Self {
+        assert!(!block_size.is_multiple_of(2), "Size must be odd");
+
+        Self {
+            method,
+            block_size,
+            offset
+        }
+    }
+
+    fn cutoffs(&self, plane: &[u8], width: usize, height: usize) -> Vec<f32> {
+        if self.method == AdaptiveMethod::Gaussian {
+            let sigma = 0.3 * ((self.block_size as f32 - 1.0) * 0.5 - 1.0) + 0.8;
+            let kernel = calculate_gaussian_kernel_1d(sigma, self.block_size as u32);
+            let values: Vec<f32> = plane.iter().map(|&v| v as f32).collect();
+
+            return separable_convolution(&values, width, height, &kernel);
+        }
+
+        // Integral images of values and squared values, with an extra zero row and column
+        let stride = width + 1;
+        let mut sum = vec![0u64; stride * (height + 1)];
+        let mut sum_sq = vec![0u64; stride * (height + 1)];
+
+        for y in 0..height {
+            let mut row = 0u64;
+            let mut row_sq = 0u64;
+
+            for x in 0..width {
+                let value = plane[y * width + x] as u64;
+                row += value;
+                row_sq += value * value;
+
+                sum[(y + 1) * stride + x + 1] = sum[y * stride + x + 1] + row;
+                sum_sq[(y + 1) * stride + x + 1] = sum_sq[y * stride + x + 1] + row_sq;
+            }
+        }
+
+        let half = self.block_size / 2;
+        let mut res = vec![0f32; width * height];
+
+        for y in 0..height {
+            let y0 = y.saturating_sub(half);
+            let y1 = (y + half + 1).min(height);
+
+            for x in 0..width {
+                let x0 = x.saturating_sub(half);
+                let x1 = (x + half + 1).min(width);
+
+                let area = |table: &[u64]| table[y1 * stride + x1] + table[y0 * stride + x0] - table[y0 * stride + x1] - table[y1 * stride + x0];
+                let count = ((x1 - x0) * (y1 - y0)) as f64;
+                let mean = area(&sum) as f64 / count;
+                let std_dev = (area(&sum_sq) as f64 / count - mean * mean).max(0.0).sqrt();
+
+                res[y * width + x] = match self.method {
+                    AdaptiveMethod::Niblack { k } => mean + k as f64 * std_dev,
+                    AdaptiveMethod::Sauvola { k, r } => mean * (1.0 + k as f64 * (std_dev / r as f64 - 1.0)),
+                    _ => mean
+                } as f32;
+            }
+        }
+
+        res
+    }
+}
+
+impl ImageFilter for AdaptiveThreshold {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let width = img.get_width();
+        let height = img.get_height();
+
+        let plane = luminance_plane(img.as_ref());
+        let cutoffs = self.cutoffs(&plane, width, height);
+
+        for j in 0..height {
+            for i in 0..width {
+                let idx = j * width + i;
+
+                if plane[idx] as f32 > cutoffs[idx] - self.offset {
+                    img.set_pixel(i, j, Colors::WHITE);
+                } else {
+                    img.set_pixel(i, j, Colors::BLACK);
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
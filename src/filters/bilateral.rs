@@ -0,0 +1,98 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+
+use super::ImageFilter;
+
+/// Edge preserving blur, neighbours are weighted by both their distance and their color difference
+pub struct Bilateral {
+    spatial_sigma: f32,
+    range_sigma: f32
+}
+
+impl Bilateral {
+    /// * `spatial_sigma` - how far the blur reaches in pixels
+    /// * `range_sigma` - how different colors still get blurred together (in 0 - 255 units)
+    /// 
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::Bilateral;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(Bilateral::new(3.0, 30.0));
+    /// ```
+    pub fn new(spatial_sigma: f32, range_sigma: f32) -> Self {
+        assert!(spatial_sigma > 0.0 && range_sigma > 0.0, "Sigma must be positive");
+
+        Self {
+            spatial_sigma,
+            range_sigma
+        }
+    }
+}
+
+impl ImageFilter for Bilateral {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let width = img.get_width() as isize;
+        let height = img.get_height() as isize;
+        let radius = (2.0 * self.spatial_sigma).ceil() as isize;
+
+        let mut source: Vec<Pixel> = Vec::with_capacity((width * height) as usize);
+        for j in 0..height {
+            for i in 0..width {
+                source.push(img.get_pixel(i as usize, j as usize).cloned().unwrap_or_default());
+            }
+        }
+
+        let size = 2 * radius + 1;
+        let mut spatial = vec![0f32; (size * size) as usize];
+        for k in -radius..=radius {
+            for l in -radius..=radius {
+                let distance = (k * k + l * l) as f32;
+                spatial[((k + radius) * size + l + radius) as usize] = (-distance / (2.0 * self.spatial_sigma.powi(2))).exp();
+            }
+        }
+
+        // Range weights for every possible squared color distance
+        let range: Vec<f32> = (0..=3 * 255 * 255)
+            .map(|distance| (-(distance as f32) / (2.0 * self.range_sigma.powi(2))).exp())
+            .collect();
+
+        for j in 0..height {
+            for i in 0..width {
+                let center = &source[(j * width + i) as usize];
+                let mut r = 0f32;
+                let mut g = 0f32;
+                let mut b = 0f32;
+                let mut total = 0f32;
+
+                for k in -radius..=radius {
+                    for l in -radius..=radius {
+                        let x = i + l;
+                        let y = j + k;
+
+                        if x < 0 || y < 0 || x >= width || y >= height {
+                            continue;
+                        }
+
+                        let pixel = &source[(y * width + x) as usize];
+                        let weight = spatial[((k + radius) * size + l + radius) as usize] * range[center.distance_squared(pixel) as usize];
+
+                        r += pixel.r as f32 * weight;
+                        g += pixel.g as f32 * weight;
+                        b += pixel.b as f32 * weight;
+                        total += weight;
+                    }
+                }
+
+                let px = Pixel {
+                    r: (r / total).round().clamp(0.0, 255.0) as u8,
+                    g: (g / total).round().clamp(0.0, 255.0) as u8,
+                    b: (b / total).round().clamp(0.0, 255.0) as u8
+                };
+
+                img.set_pixel(i as usize, j as usize, px);
+            }
+        }
+    }
+}
\ No newline at end of file
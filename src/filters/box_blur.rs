@@ -8,7 +8,7 @@ pub struct BoxBlur {
 
 impl BoxBlur {
     pub fn new(size: usize) -> Self {
-        assert!(size % 2 != 0, "Size must be odd");
+        assert!(!size.is_multiple_of(2), "Size must be odd");
 
         Self {
             size
@@ -0,0 +1,138 @@
+use std::f32::consts::PI;
+
+use crate::analysis::{Gradient, GradientOperator};
+use crate::formats::ImageFormat;
+use crate::models::Colors;
+use crate::utils::{calculate_gaussian_kernel_1d, luminance_plane, separable_convolution};
+
+use super::ImageFilter;
+
+/// Canny edge detector, produces thin white edges on black background
+pub struct Canny {
+    sigma: f32,
+    low: f32,
+    high: f32
+}
+
+impl Canny {
+    /// * `sigma` - strength of the Gaussian smoothing, 0 disables it
+    /// * `low` - weak edge threshold relative to the strongest edge (0 - 1)
+    /// * `high` - strong edge threshold relative to the strongest edge (0 - 1)
+    /// 
+    /// Weak edges are kept only when they are connected to a strong edge
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::Canny;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(Canny::new(1.4, 0.1, 0.3));
+    /// ```
+    pub fn new(sigma: f32, low: f32, high: f32) -> Self {
+        assert!(low <= high, "Low threshold must not be greater than the high threshold");
+
+        Self {
+            sigma,
+            low,
+            high
+        }
+    }
+
+    /// Runs the detector and returns whether every pixel (stored row by row) is an edge
+    pub fn detect(&self, img: &dyn ImageFormat) -> Vec<bool> {
+        let width = img.get_width();
+        let height = img.get_height();
+
+        let mut plane: Vec<f32> = luminance_plane(img).iter().map(|&v| v as f32).collect();
+        if self.sigma > 0.0 {
+            let size = 2 * (3.0 * self.sigma).ceil() as u32 + 1;
+            plane = separable_convolution(&plane, width, height, &calculate_gaussian_kernel_1d(self.sigma, size));
+        }
+
+        let gradient = Gradient::from_plane(&plane, width, height, GradientOperator::Sobel);
+        let magnitude = gradient.magnitude();
+        let direction = gradient.direction();
+
+        // Non-maximum suppression along the gradient direction
+        let mut thin = vec![0f32; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let idx = y * width + x;
+                let angle = direction[idx].rem_euclid(PI);
+
+                let (dx, dy): (isize, isize) = if !(PI / 8.0..7.0 * PI / 8.0).contains(&angle) {
+                    (1, 0)
+                } else if angle < 3.0 * PI / 8.0 {
+                    (1, 1)
+                } else if angle < 5.0 * PI / 8.0 {
+                    (0, 1)
+                } else {
+                    (-1, 1)
+                };
+
+                let neighbour = |sign: isize| {
+                    let nx = x as isize + dx * sign;
+                    let ny = y as isize + dy * sign;
+
+                    if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize {
+                        0.0
+                    } else {
+                        magnitude[ny as usize * width + nx as usize]
+                    }
+                };
+
+                if magnitude[idx] >= neighbour(1) && magnitude[idx] >= neighbour(-1) {
+                    thin[idx] = magnitude[idx];
+                }
+            }
+        }
+
+        // Hysteresis, follow weak edges from the strong ones
+        let max = thin.iter().copied().fold(0f32, f32::max);
+        let low = max * self.low;
+        let high = max * self.high;
+
+        let mut edges = vec![false; width * height];
+        let mut stack: Vec<usize> = vec![];
+
+        for (idx, &value) in thin.iter().enumerate() {
+            if value > 0.0 && value >= high {
+                edges[idx] = true;
+                stack.push(idx);
+            }
+        }
+
+        while let Some(idx) = stack.pop() {
+            let x = (idx % width) as isize;
+            let y = (idx / width) as isize;
+
+            for ny in y - 1..=y + 1 {
+                for nx in x - 1..=x + 1 {
+                    if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize {
+                        continue;
+                    }
+
+                    let next = ny as usize * width + nx as usize;
+                    if !edges[next] && thin[next] > 0.0 && thin[next] >= low {
+                        edges[next] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+        }
+
+        edges
+    }
+}
+
+impl ImageFilter for Canny {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let width = img.get_width();
+        let edges = self.detect(img.as_ref());
+
+        for (idx, edge) in edges.into_iter().enumerate() {
+            let px = if edge { Colors::WHITE } else { Colors::BLACK };
+            img.set_pixel(idx % width, idx / width, px);
+        }
+    }
+}
\ No newline at end of file
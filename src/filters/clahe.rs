@@ -0,0 +1,123 @@
+use crate::formats::ImageFormat;
+
+use super::histogram_equalization::process_planes;
+use super::ImageFilter;
+
+/// Contrast limited adaptive histogram equalization
+pub struct Clahe {
+    grid_size: usize,
+    clip_limit: f32,
+    luminance_only: bool
+}
+
+impl Clahe {
+    /// * `grid_size` - number of tiles in each direction
+    /// * `clip_limit` - maximum height of a histogram bin relative to a flat histogram (usually 2 - 4)
+    /// * `luminance_only` - equalizes only the brightness, so the colors don't shift
+    pub fn new(grid_size: usize, clip_limit: f32, luminance_only: bool) -> Self {
+        assert!(grid_size > 0, "Grid size must be positive");
+        assert!(clip_limit > 0.0, "Clip limit must be positive");
+
+        Self {
+            grid_size,
+            clip_limit,
+            luminance_only
+        }
+    }
+
+    fn tile_lut(&self, plane: &[u8], width: usize, x0: usize, x1: usize, y0: usize, y1: usize) -> [u8; 256] {
+        let mut hist = [0u32; 256];
+
+        for y in y0..y1 {
+            for &value in &plane[y * width + x0..y * width + x1] {
+                hist[value as usize] += 1;
+            }
+        }
+
+        let pixels = ((x1 - x0) * (y1 - y0)) as f32;
+        let limit = (self.clip_limit * pixels / 256.0).max(1.0) as u32;
+
+        // Clip the bins and redistribute the excess evenly
+        let mut excess = 0;
+        for freq in hist.iter_mut() {
+            if *freq > limit {
+                excess += *freq - limit;
+                *freq = limit;
+            }
+        }
+
+        let share = excess / 256;
+        let remainder = (excess % 256) as usize;
+        for (value, freq) in hist.iter_mut().enumerate() {
+            *freq += share + (value < remainder) as u32;
+        }
+
+        let mut lut = [0u8; 256];
+        let mut cdf = 0;
+        for (value, mapped) in lut.iter_mut().enumerate() {
+            cdf += hist[value];
+            *mapped = (cdf as f32 / pixels * 255.0).round().clamp(0.0, 255.0) as u8;
+        }
+
+        lut
+    }
+}
+
+impl ImageFilter for Clahe {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        process_planes(img, self.luminance_only, |plane, width, height| {
+            if width == 0 || height == 0 {
+                return;
+            }
+
+            let tiles_x = self.grid_size.min(width);
+            let tiles_y = self.grid_size.min(height);
+
+            // Tiles split the image evenly, their sizes differ by at most one pixel
+            let mut luts: Vec<[u8; 256]> = Vec::with_capacity(tiles_x * tiles_y);
+            for ty in 0..tiles_y {
+                for tx in 0..tiles_x {
+                    let (x0, x1) = (tx * width / tiles_x, (tx + 1) * width / tiles_x);
+                    let (y0, y1) = (ty * height / tiles_y, (ty + 1) * height / tiles_y);
+
+                    luts.push(self.tile_lut(plane, width, x0, x1, y0, y1));
+                }
+            }
+
+            // Bilinear interpolation between the mappings of the four nearest tile centers
+            let columns = interpolation_weights(width, tiles_x);
+            let rows = interpolation_weights(height, tiles_y);
+
+            for (y, &(ty0, ty1, wy)) in rows.iter().enumerate() {
+                for (x, &(tx0, tx1, wx)) in columns.iter().enumerate() {
+                    let value = plane[y * width + x] as usize;
+                    let top = luts[ty0 * tiles_x + tx0][value] as f32 * (1.0 - wx) + luts[ty0 * tiles_x + tx1][value] as f32 * wx;
+                    let bottom = luts[ty1 * tiles_x + tx0][value] as f32 * (1.0 - wx) + luts[ty1 * tiles_x + tx1][value] as f32 * wx;
+
+                    plane[y * width + x] = (top * (1.0 - wy) + bottom * wy).round().clamp(0.0, 255.0) as u8;
+                }
+            }
+        });
+    }
+}
+
+/// For every pixel along an axis finds the two nearest tiles and the weight of the second one
+fn interpolation_weights(size: usize, tiles: usize) -> Vec<(usize, usize, f32)> {
+    let centers: Vec<f32> = (0..tiles).map(|t| (t * size / tiles + (t + 1) * size / tiles) as f32 / 2.0).collect();
+    let mut first = 0;
+
+    (0..size).map(|i| {
+        let pos = i as f32 + 0.5;
+        while first + 2 < tiles && centers[first + 1] <= pos {
+            first += 1;
+        }
+
+        if tiles == 1 || pos <= centers[0] {
+            (0, 0, 0.0)
+        } else if pos >= centers[tiles - 1] {
+            (tiles - 1, tiles - 1, 0.0)
+        } else {
+            (first, first + 1, (pos - centers[first]) / (centers[first + 1] - centers[first]))
+        }
+    }).collect()
+}
\ No newline at end of file
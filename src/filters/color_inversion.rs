@@ -1,5 +1,6 @@
 use super::ImageFilter;
 
+#[derive(Default)]
 pub struct ColorInversion {}
 
 impl ColorInversion {
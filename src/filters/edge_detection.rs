@@ -1,119 +1,136 @@
+use crate::analysis::{Gradient, GradientOperator};
 use crate::models::{Colors, Pixel};
+use crate::utils::{calculate_gaussian_kernel_1d, luminance_plane, separable_convolution};
 
 use super::ImageFilter;
 
+/// Operator used to find the edges
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeOperator {
+    Sobel,
+    Scharr,
+    Prewitt,
+    Roberts,
+    /// Zero crossings of the Laplacian of the image smoothed with a Gaussian of the given sigma
+    LaplacianOfGaussian { sigma: f32 }
+}
+
 pub struct EdgeDetection {
+    operator: EdgeOperator,
     threshold: f32,
     multiplier: f32
 }
 
 impl EdgeDetection {
-    #[allow(dead_code)]
+    /// Detects the edges with the Sobel operator
+    /// * `threshold` - minimal edge strength relative to the strongest edge (0 - 1)
+    /// * `multiplier` - brightness multiplier of the detected edges
     pub fn new(threshold: f32, multiplier: f32) -> Self {
+        Self::with_operator(EdgeOperator::Sobel, threshold, multiplier)
+    }
+
+    /// Detects the edges with the given operator
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::{EdgeDetection, EdgeOperator};
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(EdgeDetection::with_operator(EdgeOperator::Scharr, 0.1, 1.0));
+    /// ```
+    pub fn with_operator(operator: EdgeOperator, threshold: f32, multiplier: f32) -> Self {
+        if let EdgeOperator::LaplacianOfGaussian { sigma } = operator {
+            assert!(sigma > 0.0, "Sigma of the Laplacian of Gaussian must be positive");
+        }
+
         Self { 
-            threshold, multiplier
+            operator, threshold, multiplier
         }
     }
-}
 
-impl ImageFilter for EdgeDetection {
-    // TODO: Fix this
-    fn apply(&self, img: &mut Box<dyn crate::formats::ImageFormat>) {
-        let width = img.get_width() as isize;
-        let height = img.get_height() as isize;
+    /// Calculates the edge strength of every pixel
+    fn strengths(&self, plane: &[f32], width: usize, height: usize) -> Vec<f32> {
+        let operator = match self.operator {
+            EdgeOperator::Sobel => GradientOperator::Sobel,
+            EdgeOperator::Scharr => GradientOperator::Scharr,
+            EdgeOperator::Prewitt => GradientOperator::Prewitt,
+            EdgeOperator::Roberts => GradientOperator::Roberts,
+            EdgeOperator::LaplacianOfGaussian { sigma } => {
+                return EdgeDetection::log_zero_crossings(plane, width, height, sigma);
+            }
+        };
+
+        Gradient::from_plane(plane, width, height, operator).magnitude()
+    }
 
-        let mut g_max = 0f32;
-        //let mut intensities: Vec<f32> = vec![];
+    /// Finds the sign changes of the Laplacian of Gaussian, the strength is the jump across the crossing
+    fn log_zero_crossings(plane: &[f32], width: usize, height: usize, sigma: f32) -> Vec<f32> {
+        let size = 2 * (3.0 * sigma).ceil().max(1.0) as u32 + 1;
+        let smooth = separable_convolution(plane, width, height, &calculate_gaussian_kernel_1d(sigma, size));
 
-        for i in 0..width {
-            for j in 0..height {
-                let sobel_x = EdgeDetection::sobel_x(img, i, j);
-                let sobel_y = EdgeDetection::sobel_y(img, i, j); 
+        let value = |x: isize, y: isize| {
+            smooth[y.clamp(0, height as isize - 1) as usize * width + x.clamp(0, width as isize - 1) as usize]
+        };
 
-                let g = (sobel_x.powi(2) + sobel_y.powi(2)).sqrt();
-                //intensities.push(g);
-                g_max = g_max.max(g);               
+        let mut laplacian = vec![0f32; width * height];
+        for y in 0..height as isize {
+            for x in 0..width as isize {
+                laplacian[y as usize * width + x as usize] = value(x - 1, y) + value(x + 1, y) + value(x, y - 1) + value(x, y + 1) - 4.0 * value(x, y);
             }
         }
 
-        //let threshold = (intensities.iter().sum::<f32>() / intensities.len() as f32) as u8;
+        let mut res = vec![0f32; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let current = laplacian[y * width + x];
+                let mut strength = 0f32;
 
-        for i in 0..width {
-            for j in 0..height {
-                let sobel_x = EdgeDetection::sobel_x(img, i, j);
-                let sobel_y = EdgeDetection::sobel_y(img, i, j); 
+                if x + 1 < width {
+                    let right = laplacian[y * width + x + 1];
+                    if current * right < 0.0 {
+                        strength = strength.max((current - right).abs());
+                    }
+                }
 
-                let g = (sobel_x.powi(2) + sobel_y.powi(2)).sqrt();
-                let normalized = (((g / g_max) * 255f32) * self.multiplier).min(255f32) as u8;
-                let threshold = g_max * self.threshold;
-
-                // let px: Pixel = Pixel {
-                //     r: normalized,
-                //     g: normalized,
-                //     b: normalized
-                // };
-                let px: Pixel;
-
-                if g > threshold {
-                    px = Pixel {
-                        r: normalized,
-                        g: normalized,
-                        b: normalized
-                    };
-                } else {
-                    px = Colors::BLACK;
+                if y + 1 < height {
+                    let below = laplacian[(y + 1) * width + x];
+                    if current * below < 0.0 {
+                        strength = strength.max((current - below).abs());
+                    }
                 }
 
-                img.set_pixel(i as usize, j as usize, px);
+                res[y * width + x] = strength;
             }
         }
+
+        res
     }
 }
 
-impl EdgeDetection {
-    fn sobel_x(img: &Box<dyn crate::formats::ImageFormat>, x: isize, y: isize) -> f32 {
-        let x_kernel = vec![
-            vec![-1, 0, 1],
-            vec![-2, 0, 2],
-            vec![-1, 0, 1]
-        ];
-    
-        let mut gx = 0.0;
-    
-        for i in -1 as isize..=1 {
-            for j in -1 as isize..=1 {
-                if let Some(pixel) = img.get_pixel((x + i) as usize, (y + j) as usize) {
-                    let kernel_val = x_kernel[(i + 1) as usize][(j + 1) as usize] as f32;
-                    if kernel_val != 0.0 {
-                        gx += pixel.r as f32 * kernel_val;
-                    }
-                }
-            }
-        }
-    
-        gx
-    }
+impl ImageFilter for EdgeDetection {
+    fn apply(&self, img: &mut Box<dyn crate::formats::ImageFormat>) {
+        let width = img.get_width();
+        let height = img.get_height();
 
-    fn sobel_y(img: &Box<dyn crate::formats::ImageFormat>, x: isize, y: isize) -> f32 {
-        let y_kernel = vec![
-            vec![-1, -2, -1],
-            vec![0, 0, 0],
-            vec![1, 2, 1]
-        ];
-    
-        let mut gy = 0.0;
-    
-        for i in -1 as isize..=1 {
-            for j in -1 as isize..=1 {
-                if let Some(pixel) = img.get_pixel((x + i) as usize, (y + j) as usize) {
-                    let kernel_val = y_kernel[(i + 1) as usize][(j + 1) as usize] as f32;
-                    if kernel_val != 0.0 {
-                        gy += pixel.r as f32 * kernel_val;
-                    }
+        let plane: Vec<f32> = luminance_plane(img.as_ref()).iter().map(|&v| v as f32).collect();
+        let strengths = self.strengths(&plane, width, height);
+        let g_max = strengths.iter().copied().fold(0f32, f32::max);
+        let threshold = g_max * self.threshold;
+
+        for (idx, &g) in strengths.iter().enumerate() {
+            let px = if g > threshold {
+                let normalized = (((g / g_max) * 255f32) * self.multiplier).min(255f32) as u8;
+
+                Pixel {
+                    r: normalized,
+                    g: normalized,
+                    b: normalized
                 }
-            }
+            } else {
+                Colors::BLACK
+            };
+
+            img.set_pixel(idx % width, idx / width, px);
         }
-    
-        gy
     }
 }
\ No newline at end of file
@@ -1,65 +1,99 @@
 use crate::models::Pixel;
+use crate::utils::luminance_plane;
 
-use super::{Grayscale, ImageFilter};
+use super::ImageFilter;
 
-pub struct Emboss {}
+/// Lights the image as if its brightness was a height map
+pub struct Emboss {
+    azimuth: f32,
+    elevation: f32,
+    depth: f32,
+    colored: bool
+}
 
 impl Emboss {
-    #[allow(dead_code)]
-    pub fn new() -> Self {
-        Self {}
+    /// * `azimuth` - direction of the light in degrees, 0 lights from the right, 90 from the top
+    /// * `elevation` - angle of the light above the image in degrees (0 - 90)
+    /// * `depth` - height of the relief, higher values give stronger shading
+    /// * `colored` - shades the original colors instead of producing a gray image
+    /// 
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::Emboss;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(Emboss::new(135.0, 45.0, 2.0, false));
+    /// ```
+    pub fn new(azimuth: f32, elevation: f32, depth: f32, colored: bool) -> Self {
+        assert!(depth >= 0.0, "Depth must not be negative");
+
+        Self {
+            azimuth,
+            elevation: elevation.clamp(0.0, 90.0),
+            depth,
+            colored
+        }
+    }
+}
+
+impl Default for Emboss {
+    fn default() -> Self {
+        Self::new(135.0, 45.0, 1.0, false)
     }
 }
 
 impl ImageFilter for Emboss {
-    #[allow(unreachable_code)]
     fn apply(&self, img: &mut Box<dyn crate::formats::ImageFormat>) {
-        todo!();
         let width = img.get_width() as isize;
         let height = img.get_height() as isize;
+        let plane = luminance_plane(img.as_ref());
 
-        let kernel = vec![
-            vec![1, 1, 0],
-            vec![1, 0, -1],
-            vec![0, -1, -1],
-        ];
+        let azimuth = self.azimuth.to_radians();
+        let elevation = self.elevation.to_radians();
+        let light = (azimuth.cos() * elevation.cos(), azimuth.sin() * elevation.cos(), elevation.sin());
+
+        let value = |x: isize, y: isize| {
+            let x = x.clamp(0, width - 1);
+            let y = y.clamp(0, height - 1);
+
+            plane[(y * width + x) as usize] as f32 / 255.0
+        };
 
         for i in 0..width {
             for j in 0..height {
-                let mut r = 0;
-                let mut g = 0;
-                let mut b = 0;
+                // Sobel gradient, rows are stored bottom up so y grows towards the top
+                let dx = (value(i + 1, j - 1) + 2.0 * value(i + 1, j) + value(i + 1, j + 1))
+                    - (value(i - 1, j - 1) + 2.0 * value(i - 1, j) + value(i - 1, j + 1));
+                let dy = (value(i - 1, j + 1) + 2.0 * value(i, j + 1) + value(i + 1, j + 1))
+                    - (value(i - 1, j - 1) + 2.0 * value(i, j - 1) + value(i + 1, j - 1));
 
-                for k in -1..=1 {
-                    for l in -1..=1 {
-                        let x = (i + k).clamp(0, width - 1) as usize;
-                        let y = (j + l).clamp(0, height - 1) as usize;
-                        let kernel_x = (k + 1).clamp(0, width - 1) as usize;
-                        let kernel_y = (l + 1).clamp(0, height - 1) as usize;
+                let normal = (-dx * self.depth, -dy * self.depth, 1.0);
+                let length = (normal.0 * normal.0 + normal.1 * normal.1 + 1.0).sqrt();
+                let shade = ((normal.0 * light.0 + normal.1 * light.1 + normal.2 * light.2) / length).max(0.0);
 
-                        if let Some(pixel) = img.get_pixel(x, y) {
-                            let weight = kernel[kernel_x][kernel_y];
-                            r += pixel.r as i32 * weight;
-                            g += pixel.g as i32 * weight;
-                            b += pixel.b as i32 * weight;
-                        }
-                    }
-                }
+                let px = if self.colored {
+                    // Flat areas keep their original color
+                    let scale = if light.2 > 0.0 { shade / light.2 } else { shade };
+                    let pixel = img.get_pixel(i as usize, j as usize).cloned().unwrap_or_default();
 
-                r = (r + 128).clamp(0, 255);
-                g = (g + 128).clamp(0, 255);
-                b = (b + 128).clamp(0, 255);
+                    Pixel {
+                        r: (pixel.r as f32 * scale).round().clamp(0.0, 255.0) as u8,
+                        g: (pixel.g as f32 * scale).round().clamp(0.0, 255.0) as u8,
+                        b: (pixel.b as f32 * scale).round().clamp(0.0, 255.0) as u8
+                    }
+                } else {
+                    let gray = (shade * 255.0).round().clamp(0.0, 255.0) as u8;
 
-                let px = Pixel {
-                    r: r as u8,
-                    g: g as u8,
-                    b: b as u8,
+                    Pixel {
+                        r: gray,
+                        g: gray,
+                        b: gray
+                    }
                 };
 
                 img.set_pixel(i as usize, j as usize, px);
             }
         }
-
-        Grayscale::new().apply(img);
     }
 }
\ No newline at end of file
@@ -0,0 +1,143 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+
+use super::ImageFilter;
+
+/// How the quantization error is spread to the neighbouring pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffusionKernel {
+    FloydSteinberg,
+    JarvisJudiceNinke,
+    Stucki,
+    /// Spreads only 3/4 of the error, which keeps more contrast
+    Atkinson,
+    Sierra
+}
+
+impl DiffusionKernel {
+    /// Gets the (dx, dy, weight) offsets and the divisor of the weights
+    fn weights(&self) -> (&'static [(isize, usize, f32)], f32) {
+        match self {
+            DiffusionKernel::FloydSteinberg => (&[
+                (1, 0, 7.0),
+                (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)
+            ], 16.0),
+
+            DiffusionKernel::JarvisJudiceNinke => (&[
+                (1, 0, 7.0), (2, 0, 5.0),
+                (-2, 1, 3.0), (-1, 1, 5.0), (0, 1, 7.0), (1, 1, 5.0), (2, 1, 3.0),
+                (-2, 2, 1.0), (-1, 2, 3.0), (0, 2, 5.0), (1, 2, 3.0), (2, 2, 1.0)
+            ], 48.0),
+
+            DiffusionKernel::Stucki => (&[
+                (1, 0, 8.0), (2, 0, 4.0),
+                (-2, 1, 2.0), (-1, 1, 4.0), (0, 1, 8.0), (1, 1, 4.0), (2, 1, 2.0),
+                (-2, 2, 1.0), (-1, 2, 2.0), (0, 2, 4.0), (1, 2, 2.0), (2, 2, 1.0)
+            ], 42.0),
+
+            DiffusionKernel::Atkinson => (&[
+                (1, 0, 1.0), (2, 0, 1.0),
+                (-1, 1, 1.0), (0, 1, 1.0), (1, 1, 1.0),
+                (0, 2, 1.0)
+            ], 8.0),
+
+            DiffusionKernel::Sierra => (&[
+                (1, 0, 5.0), (2, 0, 3.0),
+                (-2, 1, 2.0), (-1, 1, 4.0), (0, 1, 5.0), (1, 1, 4.0), (2, 1, 2.0),
+                (-1, 2, 2.0), (0, 2, 3.0), (1, 2, 2.0)
+            ], 32.0)
+        }
+    }
+}
+
+/// Maps the image to a palette and spreads the error of every pixel to its unprocessed neighbours
+pub struct ErrorDiffusion {
+    kernel: DiffusionKernel,
+    palette: Vec<Pixel>,
+    serpentine: bool
+}
+
+impl ErrorDiffusion {
+    /// * `kernel` - weights used to spread the error
+    /// * `palette` - colors of the result
+    /// * `serpentine` - processes every other row from right to left, which reduces directional artefacts
+    /// 
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::{DiffusionKernel, ErrorDiffusion};
+    /// use rusty_imager::models::Colors;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(ErrorDiffusion::new(DiffusionKernel::FloydSteinberg, vec![Colors::BLACK, Colors::WHITE], true));
+    /// ```
+    pub fn new(kernel: DiffusionKernel, palette: Vec<Pixel>, serpentine: bool) -> Self {
+        assert!(!palette.is_empty(), "Palette must not be empty");
+
+        Self {
+            kernel,
+            palette,
+            serpentine
+        }
+    }
+}
+
+impl ImageFilter for ErrorDiffusion {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let width = img.get_width();
+        let height = img.get_height();
+        let (weights, divisor) = self.kernel.weights();
+
+        let mut buffer: Vec<[f32; 3]> = Vec::with_capacity(width * height);
+        for j in 0..height {
+            for i in 0..width {
+                let pixel = img.get_pixel(i, j).cloned().unwrap_or_default();
+                buffer.push([pixel.r as f32, pixel.g as f32, pixel.b as f32]);
+            }
+        }
+
+        for j in 0..height {
+            let reverse = self.serpentine && j % 2 == 1;
+
+            for step in 0..width {
+                let i = if reverse { width - 1 - step } else { step };
+                let old = buffer[j * width + i];
+                let new = nearest_color(&self.palette, old);
+                let error = [old[0] - new.r as f32, old[1] - new.g as f32, old[2] - new.b as f32];
+
+                img.set_pixel(i, j, new.clone());
+
+                for &(dx, dy, weight) in weights {
+                    let dx = if reverse { -dx } else { dx };
+                    let x = i as isize + dx;
+                    let y = j + dy;
+
+                    if x < 0 || x >= width as isize || y >= height {
+                        continue;
+                    }
+
+                    let target = &mut buffer[y * width + x as usize];
+                    for channel in 0..3 {
+                        target[channel] += error[channel] * weight / divisor;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Finds the palette color closest to the (possibly out of range) color
+pub(super) fn nearest_color(palette: &[Pixel], color: [f32; 3]) -> &Pixel {
+    let r = color[0].clamp(0.0, 255.0);
+    let g = color[1].clamp(0.0, 255.0);
+    let b = color[2].clamp(0.0, 255.0);
+
+    palette.iter()
+        .min_by(|x, y| {
+            let dx = (x.r as f32 - r).powi(2) + (x.g as f32 - g).powi(2) + (x.b as f32 - b).powi(2);
+            let dy = (y.r as f32 - r).powi(2) + (y.g as f32 - g).powi(2) + (y.b as f32 - b).powi(2);
+
+            dx.total_cmp(&dy)
+        })
+        .unwrap()
+}
\ No newline at end of file
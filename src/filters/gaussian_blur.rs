@@ -9,7 +9,7 @@ pub struct GaussianBlur {
 
 impl GaussianBlur {
     pub fn new(intensity: f32, kernel_size: u32) -> Self {
-        assert!(kernel_size % 2 != 0, "Size must be odd");
+        assert!(!kernel_size.is_multiple_of(2), "Size must be odd");
 
         Self {
             intensity,
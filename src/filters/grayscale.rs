@@ -2,6 +2,7 @@ use crate::models::Pixel;
 
 use super::ImageFilter;
 
+#[derive(Default)]
 pub struct Grayscale;
 
 impl Grayscale {
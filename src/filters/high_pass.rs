@@ -0,0 +1,39 @@
+use crate::formats::ImageFormat;
+use crate::utils::{calculate_gaussian_kernel_1d, separable_convolution};
+
+use super::histogram_equalization::process_planes;
+use super::ImageFilter;
+
+/// Keeps only the details smaller than the radius, flat areas become middle gray
+/// 
+/// Blending the result over the original (overlay / soft light) gives a controllable sharpening
+pub struct HighPass {
+    radius: f32
+}
+
+impl HighPass {
+    /// * `radius` - sigma of the Gaussian blur that is subtracted
+    pub fn new(radius: f32) -> Self {
+        assert!(radius > 0.0, "Radius must be positive");
+
+        Self {
+            radius
+        }
+    }
+}
+
+impl ImageFilter for HighPass {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let size = 2 * (3.0 * self.radius).ceil() as u32 + 1;
+        let kernel = calculate_gaussian_kernel_1d(self.radius, size);
+
+        process_planes(img, false, |plane, width, height| {
+            let values: Vec<f32> = plane.iter().map(|&v| v as f32).collect();
+            let blurred = separable_convolution(&values, width, height, &kernel);
+
+            for (value, blur) in plane.iter_mut().zip(blurred) {
+                *value = (*value as f32 - blur + 128.0).round().clamp(0.0, 255.0) as u8;
+            }
+        });
+    }
+}
\ No newline at end of file
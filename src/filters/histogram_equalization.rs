@@ -0,0 +1,106 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+
+use super::ImageFilter;
+
+pub struct HistogramEqualization {
+    luminance_only: bool
+}
+
+impl HistogramEqualization {
+    /// * `luminance_only` - equalizes only the brightness, so the colors don't shift
+    pub fn new(luminance_only: bool) -> Self {
+        Self {
+            luminance_only
+        }
+    }
+}
+
+impl ImageFilter for HistogramEqualization {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        process_planes(img, self.luminance_only, |plane, _, _| {
+            let mut hist = [0u32; 256];
+
+            for &value in plane.iter() {
+                hist[value as usize] += 1;
+            }
+
+            let lut = equalization_lut(&hist);
+
+            for value in plane.iter_mut() {
+                *value = lut[*value as usize];
+            }
+        });
+    }
+}
+
+/// Calculates the mapping that spreads the histogram over the whole 0-255 range
+fn equalization_lut(hist: &[u32; 256]) -> [u8; 256] {
+    let mut lut = [0u8; 256];
+    let total: u32 = hist.iter().sum();
+    let cdf_min = hist.iter().copied().find(|&freq| freq > 0).unwrap_or(0);
+
+    if total == cdf_min {
+        // Single value, nothing to spread
+        for (value, mapped) in lut.iter_mut().enumerate() {
+            *mapped = value as u8;
+        }
+
+        return lut;
+    }
+
+    let mut cdf = 0u32;
+    for (value, mapped) in lut.iter_mut().enumerate() {
+        cdf += hist[value];
+        let scaled = cdf.saturating_sub(cdf_min) as f32 / (total - cdf_min) as f32 * 255.0;
+        *mapped = scaled.round().clamp(0.0, 255.0) as u8;
+    }
+
+    lut
+}
+
+/// Runs `process` on every color plane of the image (or only on the luma plane)
+/// 
+/// Planes are stored row by row, `process` receives the plane with its width and height
+pub(super) fn process_planes(img: &mut Box<dyn ImageFormat>, luminance_only: bool, process: impl Fn(&mut [u8], usize, usize)) {
+    let width = img.get_width();
+    let height = img.get_height();
+
+    let mut pixels: Vec<Pixel> = Vec::with_capacity(width * height);
+    for j in 0..height {
+        for i in 0..width {
+            pixels.push(img.get_pixel(i, j).cloned().unwrap_or_default());
+        }
+    }
+
+    if luminance_only {
+        let ycbcr: Vec<(f32, f32, f32)> = pixels.iter().map(|p| p.to_ycbcr()).collect();
+        let mut luma: Vec<u8> = ycbcr.iter().map(|c| c.0.round().clamp(0.0, 255.0) as u8).collect();
+
+        process(&mut luma, width, height);
+
+        for (idx, &(_, cb, cr)) in ycbcr.iter().enumerate() {
+            pixels[idx] = Pixel::from_ycbcr(luma[idx] as f32, cb, cr);
+        }
+    } else {
+        let mut red: Vec<u8> = pixels.iter().map(|p| p.r).collect();
+        let mut green: Vec<u8> = pixels.iter().map(|p| p.g).collect();
+        let mut blue: Vec<u8> = pixels.iter().map(|p| p.b).collect();
+
+        process(&mut red, width, height);
+        process(&mut green, width, height);
+        process(&mut blue, width, height);
+
+        for (idx, pixel) in pixels.iter_mut().enumerate() {
+            *pixel = Pixel {
+                r: red[idx],
+                g: green[idx],
+                b: blue[idx]
+            };
+        }
+    }
+
+    for (idx, pixel) in pixels.into_iter().enumerate() {
+        img.set_pixel(idx % width, idx / width, pixel);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+use crate::formats::ImageFormat;
+
+use super::histogram_equalization::process_planes;
+use super::ImageFilter;
+
+/// Replaces every pixel with the median of its neighbourhood, removes salt and pepper noise while keeping the edges
+pub struct Median {
+    radius: usize
+}
+
+impl Median {
+    /// * `radius` - the neighbourhood is a (2 * radius + 1) square
+    pub fn new(radius: usize) -> Self {
+        Self {
+            radius
+        }
+    }
+}
+
+impl ImageFilter for Median {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let radius = self.radius as isize;
+
+        process_planes(img, false, |plane, width, height| {
+            if width == 0 || height == 0 {
+                return;
+            }
+
+            let source = plane.to_vec();
+            let count = ((2 * radius + 1) * (2 * radius + 1)) as u32;
+            let value = |x: isize, y: isize| {
+                source[y.clamp(0, height as isize - 1) as usize * width + x.clamp(0, width as isize - 1) as usize] as usize
+            };
+
+            for y in 0..height as isize {
+                // Two level histogram of the window, it slides along the row (Huang's algorithm)
+                let mut fine = [0u32; 256];
+                let mut coarse = [0u32; 16];
+
+                for k in -radius..=radius {
+                    for l in -radius..=radius {
+                        let v = value(l, y + k);
+                        fine[v] += 1;
+                        coarse[v >> 4] += 1;
+                    }
+                }
+
+                for x in 0..width as isize {
+                    if x > 0 {
+                        for k in -radius..=radius {
+                            let old = value(x - radius - 1, y + k);
+                            fine[old] -= 1;
+                            coarse[old >> 4] -= 1;
+
+                            let new = value(x + radius, y + k);
+                            fine[new] += 1;
+                            coarse[new >> 4] += 1;
+                        }
+                    }
+
+                    let half = count / 2 + 1;
+                    let mut sum = 0;
+                    let mut bucket = 0;
+
+                    while sum + coarse[bucket] < half {
+                        sum += coarse[bucket];
+                        bucket += 1;
+                    }
+
+                    let mut median = bucket << 4;
+                    while sum + fine[median] < half {
+                        sum += fine[median];
+                        median += 1;
+                    }
+
+                    plane[y as usize * width + x as usize] = median as u8;
+                }
+            }
+        });
+    }
+}
\ No newline at end of file
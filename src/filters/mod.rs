@@ -10,6 +10,20 @@ mod edge_detection;
 mod emboss;
 mod oil_painting;
 mod sharpen;
+mod histogram_equalization;
+mod clahe;
+mod adaptive_threshold;
+mod white_balance;
+mod error_diffusion;
+mod ordered_dither;
+mod canny;
+mod median;
+mod bilateral;
+mod non_local_means;
+mod unsharp_mask;
+mod high_pass;
+mod morphology;
+mod noise;
 
 pub use filter::ImageFilter;
 pub use gaussian_blur::GaussianBlur;
@@ -19,7 +33,21 @@ pub use contrast::Contrast;
 pub use brightness::Brightness;
 pub use threshold::Threshold;
 pub use box_blur::BoxBlur;
-pub use edge_detection::EdgeDetection;
+pub use edge_detection::{EdgeDetection, EdgeOperator};
 pub use emboss::Emboss;
 pub use oil_painting::OilPainting;
-pub use sharpen::Sharpen;
\ No newline at end of file
+pub use sharpen::Sharpen;
+pub use histogram_equalization::HistogramEqualization;
+pub use clahe::Clahe;
+pub use adaptive_threshold::{AdaptiveMethod, AdaptiveThreshold};
+pub use white_balance::WhiteBalance;
+pub use error_diffusion::{DiffusionKernel, ErrorDiffusion};
+pub use ordered_dither::OrderedDither;
+pub use canny::Canny;
+pub use median::Median;
+pub use bilateral::Bilateral;
+pub use non_local_means::NonLocalMeans;
+pub use unsharp_mask::UnsharpMask;
+pub use high_pass::HighPass;
+pub use morphology::{MorphOperation, Morphology, StructuringElement};
+pub use noise::{GaussianNoise, SaltAndPepper, UniformNoise};
\ No newline at end of file
@@ -0,0 +1,171 @@
+use crate::formats::ImageFormat;
+
+use super::histogram_equalization::process_planes;
+use super::ImageFilter;
+
+/// Shape of the neighbourhood used by the morphological operations, anchored at its center
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuringElement {
+    width: usize,
+    height: usize,
+    mask: Vec<bool>
+}
+
+impl StructuringElement {
+    pub fn rect(width: usize, height: usize) -> Self {
+        Self::custom(width, height, vec![true; width * height])
+    }
+
+    pub fn cross(width: usize, height: usize) -> Self {
+        let mask = (0..width * height)
+            .map(|idx| idx % width == width / 2 || idx / width == height / 2)
+            .collect();
+
+        Self::custom(width, height, mask)
+    }
+
+    /// Ellipse inscribed in the element, built from the extent of every row like in OpenCV (3x3 gives the cross)
+    pub fn ellipse(width: usize, height: usize) -> Self {
+        let rx = (width / 2) as f32;
+        let ry = (height / 2) as f32;
+        let mut mask = vec![false; width * height];
+
+        for y in 0..height {
+            let dy = y as f32 - ry;
+            let extent = if ry > 0.0 { (rx * (1.0 - (dy / ry).powi(2)).max(0.0).sqrt()).round() } else { rx };
+            let x0 = (rx - extent).max(0.0) as usize;
+            let x1 = ((rx + extent) as usize + 1).min(width);
+
+            mask[y * width + x0..y * width + x1].fill(true);
+        }
+
+        Self::custom(width, height, mask)
+    }
+
+    /// * `mask` - which cells of the element are used, stored row by row
+    pub fn custom(width: usize, height: usize, mask: Vec<bool>) -> Self {
+        assert!(width > 0 && height > 0, "Element must not be empty");
+        assert_eq!(mask.len(), width * height, "Mask size must match the element size");
+
+        Self {
+            width,
+            height,
+            mask
+        }
+    }
+
+    /// Offsets of the used cells relative to the anchor
+    fn offsets(&self) -> Vec<(isize, isize)> {
+        self.mask.iter()
+            .enumerate()
+            .filter(|(_, &used)| used)
+            .map(|(idx, _)| ((idx % self.width) as isize - (self.width / 2) as isize, (idx / self.width) as isize - (self.height / 2) as isize))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MorphOperation {
+    /// Minimum of the neighbourhood, shrinks bright areas
+    Erode,
+    /// Maximum of the neighbourhood, grows bright areas
+    Dilate,
+    /// Erosion followed by dilation, removes small bright specks
+    Open,
+    /// Dilation followed by erosion, fills small dark gaps
+    Close,
+    /// Difference between dilation and erosion, outlines the shapes
+    Gradient,
+    /// Difference between the image and its opening, keeps small bright details
+    TopHat,
+    /// Difference between the closing and the image, keeps small dark details
+    BlackHat
+}
+
+/// Morphological operation applied to every channel, works for grayscale and binary images
+pub struct Morphology {
+    operation: MorphOperation,
+    element: StructuringElement,
+    iterations: usize
+}
+
+impl Morphology {
+    /// * `iterations` - how many times erosion and dilation are repeated
+    /// 
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::{MorphOperation, Morphology, StructuringElement, Threshold};
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(Threshold::otsu());
+    /// img.apply_filter(Morphology::new(MorphOperation::Open, StructuringElement::ellipse(3, 3), 1));
+    /// ```
+    pub fn new(operation: MorphOperation, element: StructuringElement, iterations: usize) -> Self {
+        Self {
+            operation,
+            element,
+            iterations
+        }
+    }
+}
+
+impl ImageFilter for Morphology {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let offsets = self.element.offsets();
+
+        process_planes(img, false, |plane, width, height| {
+            let repeat = |source: &[u8], dilate: bool| {
+                let mut res = source.to_vec();
+                for _ in 0..self.iterations {
+                    res = extremum(&res, width, height, &offsets, dilate);
+                }
+
+                res
+            };
+
+            let result = match self.operation {
+                MorphOperation::Erode => repeat(plane, false),
+                MorphOperation::Dilate => repeat(plane, true),
+                MorphOperation::Open => repeat(&repeat(plane, false), true),
+                MorphOperation::Close => repeat(&repeat(plane, true), false),
+                MorphOperation::Gradient => difference(&repeat(plane, true), &repeat(plane, false)),
+                MorphOperation::TopHat => difference(plane, &repeat(&repeat(plane, false), true)),
+                MorphOperation::BlackHat => difference(&repeat(&repeat(plane, true), false), plane)
+            };
+
+            plane.copy_from_slice(&result);
+        });
+    }
+}
+
+/// Maximum (dilation) or minimum (erosion) over the element, cells outside of the plane are ignored
+fn extremum(plane: &[u8], width: usize, height: usize, offsets: &[(isize, isize)], dilate: bool) -> Vec<u8> {
+    let mut res = vec![0u8; plane.len()];
+
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            let mut value = if dilate { u8::MIN } else { u8::MAX };
+
+            for &(dx, dy) in offsets {
+                // Dilation uses the reflected element
+                let (nx, ny) = if dilate { (x - dx, y - dy) } else { (x + dx, y + dy) };
+
+                if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize {
+                    continue;
+                }
+
+                let other = plane[ny as usize * width + nx as usize];
+                value = if dilate { value.max(other) } else { value.min(other) };
+            }
+
+            res[y as usize * width + x as usize] = value;
+        }
+    }
+
+    res
+}
+
+fn difference(a: &[u8], b: &[u8]) -> Vec<u8> {
+    a.iter().zip(b).map(|(a, b)| a.saturating_sub(*b)).collect()
+}
\ No newline at end of file
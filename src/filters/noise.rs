@@ -0,0 +1,130 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+use crate::utils::Random;
+
+use super::ImageFilter;
+
+/// Adds independent normally distributed noise to every channel
+pub struct GaussianNoise {
+    sigma: f32,
+    seed: u64
+}
+
+impl GaussianNoise {
+    /// * `sigma` - standard deviation of the noise in channel values
+    /// * `seed` - the same seed always produces the same noise
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::{GaussianNoise, NonLocalMeans};
+    ///
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(GaussianNoise::new(20.0, 1));
+    /// img.apply_filter(NonLocalMeans::new(20.0, 1, 5));
+    /// ```
+    pub fn new(sigma: f32, seed: u64) -> Self {
+        assert!(sigma >= 0.0, "Sigma must not be negative");
+
+        Self {
+            sigma,
+            seed
+        }
+    }
+}
+
+impl ImageFilter for GaussianNoise {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let mut random = Random::new(self.seed);
+
+        add_noise(img, || {
+            // Box-Muller transform, the first number is kept away from zero for the logarithm
+            let u = 1.0 - random.next_f32();
+            let v = random.next_f32();
+
+            self.sigma * (-2.0 * u.ln()).sqrt() * (std::f32::consts::TAU * v).cos()
+        });
+    }
+}
+
+/// Adds independent uniformly distributed noise to every channel
+pub struct UniformNoise {
+    amplitude: f32,
+    seed: u64
+}
+
+impl UniformNoise {
+    /// * `amplitude` - the noise is in range -amplitude..amplitude
+    /// * `seed` - the same seed always produces the same noise
+    pub fn new(amplitude: f32, seed: u64) -> Self {
+        assert!(amplitude >= 0.0, "Amplitude must not be negative");
+
+        Self {
+            amplitude,
+            seed
+        }
+    }
+}
+
+impl ImageFilter for UniformNoise {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let mut random = Random::new(self.seed);
+
+        add_noise(img, || (random.next_f32() * 2.0 - 1.0) * self.amplitude);
+    }
+}
+
+/// Replaces random pixels with black or white
+pub struct SaltAndPepper {
+    density: f32,
+    seed: u64
+}
+
+impl SaltAndPepper {
+    /// * `density` - fraction (0 - 1) of the replaced pixels, half of them become white
+    /// * `seed` - the same seed always produces the same noise
+    pub fn new(density: f32, seed: u64) -> Self {
+        assert!((0.0..=1.0).contains(&density), "Density must be between 0 and 1");
+
+        Self {
+            density,
+            seed
+        }
+    }
+}
+
+impl ImageFilter for SaltAndPepper {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let mut random = Random::new(self.seed);
+
+        for y in 0..img.get_height() {
+            for x in 0..img.get_width() {
+                let roll = random.next_f32();
+                if roll >= self.density {
+                    continue;
+                }
+
+                let value = if roll < self.density / 2.0 { 0 } else { 255 };
+                img.set_pixel(x, y, Pixel { r: value, g: value, b: value });
+            }
+        }
+    }
+}
+
+fn add_noise(img: &mut Box<dyn ImageFormat>, mut noise: impl FnMut() -> f32) {
+    for y in 0..img.get_height() {
+        for x in 0..img.get_width() {
+            let Some(pixel) = img.get_pixel(x, y) else {
+                continue;
+            };
+
+            let mut channel = |value: u8| (value as f32 + noise()).round().clamp(0.0, 255.0) as u8;
+            let noisy = Pixel {
+                r: channel(pixel.r),
+                g: channel(pixel.g),
+                b: channel(pixel.b)
+            };
+
+            img.set_pixel(x, y, noisy);
+        }
+    }
+}
\ No newline at end of file
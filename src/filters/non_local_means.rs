@@ -0,0 +1,117 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+
+use super::ImageFilter;
+
+/// Averages pixels whose surrounding patches look alike, even when they are far apart
+pub struct NonLocalMeans {
+    strength: f32,
+    patch_radius: usize,
+    search_radius: usize
+}
+
+impl NonLocalMeans {
+    /// * `strength` - how different patches still get averaged (in 0 - 255 units), roughly the noise level
+    /// * `patch_radius` - size of the compared patches, usually 1 - 3
+    /// * `search_radius` - how far similar patches are searched for, usually 5 - 10
+    /// 
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::NonLocalMeans;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(NonLocalMeans::new(10.0, 3, 10));
+    /// ```
+    pub fn new(strength: f32, patch_radius: usize, search_radius: usize) -> Self {
+        assert!(strength > 0.0, "Strength must be positive");
+
+        Self {
+            strength,
+            patch_radius,
+            search_radius
+        }
+    }
+}
+
+impl ImageFilter for NonLocalMeans {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let width = img.get_width();
+        let height = img.get_height();
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let mut source: Vec<[f32; 3]> = Vec::with_capacity(width * height);
+        for j in 0..height {
+            for i in 0..width {
+                let pixel = img.get_pixel(i, j).cloned().unwrap_or_default();
+                source.push([pixel.r as f32, pixel.g as f32, pixel.b as f32]);
+            }
+        }
+
+        let at = |x: isize, y: isize| {
+            (y.clamp(0, height as isize - 1) as usize) * width + x.clamp(0, width as isize - 1) as usize
+        };
+
+        let search = self.search_radius as isize;
+        let patch = self.patch_radius as isize;
+        let h2 = (self.strength * self.strength) as f64;
+
+        let mut sums = vec![[0f64; 3]; width * height];
+        let mut totals = vec![0f64; width * height];
+
+        // Patch distances for one offset at a time, summed with an integral image
+        let stride = width + 1;
+        let mut integral = vec![0f64; stride * (height + 1)];
+
+        for dy in -search..=search {
+            for dx in -search..=search {
+                for y in 0..height {
+                    let mut row = 0f64;
+
+                    for x in 0..width {
+                        let a = &source[y * width + x];
+                        let b = &source[at(x as isize + dx, y as isize + dy)];
+                        row += ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)) as f64;
+
+                        integral[(y + 1) * stride + x + 1] = integral[y * stride + x + 1] + row;
+                    }
+                }
+
+                for y in 0..height as isize {
+                    let y0 = (y - patch).max(0) as usize;
+                    let y1 = (y + patch + 1).min(height as isize) as usize;
+
+                    for x in 0..width as isize {
+                        let x0 = (x - patch).max(0) as usize;
+                        let x1 = (x + patch + 1).min(width as isize) as usize;
+
+                        let distance = integral[y1 * stride + x1] + integral[y0 * stride + x0] - integral[y0 * stride + x1] - integral[y1 * stride + x0];
+                        let area = ((x1 - x0) * (y1 - y0) * 3) as f64;
+                        let weight = (-(distance / area) / h2).exp();
+
+                        let idx = y as usize * width + x as usize;
+                        let other = &source[at(x + dx, y + dy)];
+
+                        for channel in 0..3 {
+                            sums[idx][channel] += other[channel] as f64 * weight;
+                        }
+                        totals[idx] += weight;
+                    }
+                }
+            }
+        }
+
+        for (idx, (sum, total)) in sums.iter().zip(totals).enumerate() {
+            let px = Pixel {
+                r: (sum[0] / total).round().clamp(0.0, 255.0) as u8,
+                g: (sum[1] / total).round().clamp(0.0, 255.0) as u8,
+                b: (sum[2] / total).round().clamp(0.0, 255.0) as u8
+            };
+
+            img.set_pixel(idx % width, idx / width, px);
+        }
+    }
+}
\ No newline at end of file
@@ -15,7 +15,7 @@ impl OilPainting {
 
 impl ImageFilter for OilPainting {
     fn apply(&self, img: &mut Box<dyn crate::formats::ImageFormat>) {
-        assert!(self.size % 2 != 0, "Size must be odd");
+        assert!(!self.size.is_multiple_of(2), "Size must be odd");
 
         let half: isize = self.size as isize / 2;
         let width = img.get_width() as isize;
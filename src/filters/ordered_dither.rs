@@ -0,0 +1,226 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+use crate::utils::Random;
+
+use super::error_diffusion::nearest_color;
+use super::ImageFilter;
+
+/// Maps the image to a palette after offsetting every pixel by a tiled threshold matrix
+pub struct OrderedDither {
+    matrix: Vec<f32>,
+    size: usize,
+    palette: Vec<Pixel>
+}
+
+impl OrderedDither {
+    /// Uses a Bayer matrix
+    /// * `size` - size of the matrix, 2, 4 or 8
+    /// * `palette` - colors of the result
+    /// 
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::OrderedDither;
+    /// use rusty_imager::models::Colors;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(OrderedDither::bayer(4, vec![Colors::BLACK, Colors::WHITE]));
+    /// ```
+    pub fn bayer(size: usize, palette: Vec<Pixel>) -> Self {
+        assert!(matches!(size, 2 | 4 | 8), "Size must be 2, 4 or 8");
+        assert!(!palette.is_empty(), "Palette must not be empty");
+
+        let mut matrix: Vec<u32> = vec![0];
+        let mut n = 1;
+
+        while n < size {
+            let mut next = vec![0u32; 4 * n * n];
+
+            for y in 0..n {
+                for x in 0..n {
+                    let value = 4 * matrix[y * n + x];
+                    next[y * 2 * n + x] = value;
+                    next[y * 2 * n + x + n] = value + 2;
+                    next[(y + n) * 2 * n + x] = value + 3;
+                    next[(y + n) * 2 * n + x + n] = value + 1;
+                }
+            }
+
+            matrix = next;
+            n *= 2;
+        }
+
+        let cells = (size * size) as f32;
+
+        Self {
+            matrix: matrix.iter().map(|&v| (v as f32 + 0.5) / cells).collect(),
+            size,
+            palette
+        }
+    }
+
+    /// Uses a 32x32 blue noise matrix generated with the void-and-cluster method, which avoids the cross-hatch pattern of Bayer matrices
+    pub fn blue_noise(palette: Vec<Pixel>) -> Self {
+        assert!(!palette.is_empty(), "Palette must not be empty");
+
+        let size = 32;
+        let cells = (size * size) as f32;
+
+        Self {
+            matrix: void_and_cluster(size).iter().map(|&v| (v as f32 + 0.5) / cells).collect(),
+            size,
+            palette
+        }
+    }
+
+    /// Average per-channel distance between neighbouring palette colors, used as the dither amplitude
+    fn spread(&self) -> f32 {
+        if self.palette.len() < 2 {
+            return 0.0
+        }
+
+        let total: f32 = self.palette.iter()
+            .map(|a| {
+                self.palette.iter()
+                    .filter(|b| *b != a)
+                    .map(|b| a.r.abs_diff(b.r).max(a.g.abs_diff(b.g)).max(a.b.abs_diff(b.b)))
+                    .min()
+                    .unwrap_or(0) as f32
+            })
+            .sum();
+
+        total / self.palette.len() as f32
+    }
+}
+
+impl ImageFilter for OrderedDither {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let spread = self.spread();
+
+        for i in 0..img.get_width() {
+            for j in 0..img.get_height() {
+                if let Some(pixel) = img.get_pixel(i, j) {
+                    let offset = (self.matrix[(j % self.size) * self.size + i % self.size] - 0.5) * spread;
+                    let color = [pixel.r as f32 + offset, pixel.g as f32 + offset, pixel.b as f32 + offset];
+
+                    img.set_pixel(i, j, nearest_color(&self.palette, color).clone());
+                }
+            }
+        }
+    }
+}
+
+/// Generates a threshold matrix with Ulichney's void-and-cluster method
+fn void_and_cluster(size: usize) -> Vec<usize> {
+    let cells = size * size;
+    let sigma = 1.5f32;
+
+    // Gaussian weight for every toroidal offset
+    let mut weights = vec![0f32; cells];
+    for dy in 0..size {
+        for dx in 0..size {
+            let x = dx.min(size - dx) as f32;
+            let y = dy.min(size - dy) as f32;
+            weights[dy * size + dx] = (-(x * x + y * y) / (2.0 * sigma * sigma)).exp();
+        }
+    }
+
+    let update = |energy: &mut Vec<f32>, idx: usize, sign: f32| {
+        let (px, py) = (idx % size, idx / size);
+
+        for y in 0..size {
+            for x in 0..size {
+                let dx = (x + size - px) % size;
+                let dy = (y + size - py) % size;
+                energy[y * size + x] += sign * weights[dy * size + dx];
+            }
+        }
+    };
+
+    let energy_of = |pattern: &[bool], minority: bool| {
+        let mut energy = vec![0f32; cells];
+        for (idx, &value) in pattern.iter().enumerate() {
+            if value == minority {
+                update(&mut energy, idx, 1.0);
+            }
+        }
+
+        energy
+    };
+
+    // Tightest cluster is the set pixel with the highest energy, largest void the unset one with the lowest
+    let find = |pattern: &[bool], energy: &[f32], value: bool, highest: bool| {
+        pattern.iter()
+            .enumerate()
+            .filter(|(_, &v)| v == value)
+            .map(|(idx, _)| idx)
+            .max_by(|&a, &b| {
+                let order = energy[a].total_cmp(&energy[b]);
+                if highest { order } else { order.reverse() }
+            })
+            .unwrap()
+    };
+
+    // Initial pattern with ~10% of the pixels set, relaxed until it's evenly spread
+    let mut random = Random::new(size as u64);
+    let mut prototype = vec![false; cells];
+    let initial = cells / 10;
+    let mut placed = 0;
+
+    while placed < initial {
+        let idx = random.below(cells);
+
+        if !prototype[idx] {
+            prototype[idx] = true;
+            placed += 1;
+        }
+    }
+
+    let mut energy = energy_of(&prototype, true);
+    loop {
+        let cluster = find(&prototype, &energy, true, true);
+        prototype[cluster] = false;
+        update(&mut energy, cluster, -1.0);
+
+        let void = find(&prototype, &energy, false, false);
+        prototype[void] = true;
+        update(&mut energy, void, 1.0);
+
+        if void == cluster {
+            break;
+        }
+    }
+
+    let mut ranks = vec![0usize; cells];
+
+    // Phase 1, remove the prototype pixels from the tightest clusters
+    let mut pattern = prototype.clone();
+    let mut energy = energy_of(&pattern, true);
+    for rank in (0..initial).rev() {
+        let cluster = find(&pattern, &energy, true, true);
+        pattern[cluster] = false;
+        update(&mut energy, cluster, -1.0);
+        ranks[cluster] = rank;
+    }
+
+    // Phase 2, fill the largest voids up to half of the matrix
+    let mut pattern = prototype;
+    let mut energy = energy_of(&pattern, true);
+    for rank in initial..cells / 2 {
+        let void = find(&pattern, &energy, false, false);
+        pattern[void] = true;
+        update(&mut energy, void, 1.0);
+        ranks[void] = rank;
+    }
+
+    // Phase 3, unset pixels are the minority now, fill their tightest clusters
+    let mut energy = energy_of(&pattern, false);
+    for rank in cells / 2..cells {
+        let cluster = find(&pattern, &energy, false, true);
+        pattern[cluster] = true;
+        update(&mut energy, cluster, -1.0);
+        ranks[cluster] = rank;
+    }
+
+    ranks
+}
\ No newline at end of file
@@ -14,10 +14,10 @@ impl Sharpen {
 
 impl ImageFilter for Sharpen {
     fn apply(&self, img: &mut Box<dyn crate::formats::ImageFormat>) {
-        let kernel = vec![
-            vec![-1, -1, -1],
-            vec![-1, self.intensity as i32, -1],
-            vec![-1, -1, -1]
+        let kernel = [
+            [-1, -1, -1],
+            [-1, self.intensity as i32, -1],
+            [-1, -1, -1],
         ];
 
         let half: isize = kernel.len() as isize / 2;
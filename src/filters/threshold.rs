@@ -1,31 +1,75 @@
-use crate::models::Colors;
+use crate::analysis::Histogram;
+use crate::formats::ImageFormat;
+use crate::models::{Channel, Colors, Pixel};
 
 use super::ImageFilter;
 
+enum Cutoff {
+    Fixed(u16),
+    Otsu,
+    Triangle
+}
+
 pub struct Threshold {
-    treshold: u16 
+    cutoff: Cutoff
 }
 
 impl Threshold {
     pub fn new(treshold: u16) -> Self {
         Self {
-            treshold
+            cutoff: Cutoff::Fixed(treshold)
+        }
+    }
+
+    /// Picks the cutoff from the luminance histogram with Otsu's method
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::Threshold;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(Threshold::otsu());
+    /// ```
+    pub fn otsu() -> Self {
+        Self {
+            cutoff: Cutoff::Otsu
+        }
+    }
+
+    /// Picks the cutoff from the luminance histogram with the triangle method
+    pub fn triangle() -> Self {
+        Self {
+            cutoff: Cutoff::Triangle
         }
     }
 }
 
 impl ImageFilter for Threshold {
     fn apply(&self, img: &mut Box<dyn crate::formats::ImageFormat>) {
-        for i in 0..img.get_width() {
-            for j in 0..img.get_height() {
-                if let Some(pixel) = img.get_pixel(i, j) {
-                    let color = (pixel.r as u16 + pixel.g as u16 + pixel.b as u16) / 3;
-
-                    if color > self.treshold {
-                        img.set_pixel(i, j, Colors::WHITE);
-                    } else {
-                        img.set_pixel(i, j, Colors::BLACK);
-                    }
+        let auto = match self.cutoff {
+            Cutoff::Fixed(treshold) => {
+                binarize(img, treshold, |pixel| (pixel.r as u16 + pixel.g as u16 + pixel.b as u16) / 3);
+                return;
+            }
+
+            Cutoff::Otsu => Histogram::from_image(img.as_ref()).otsu_threshold(Channel::Luminance),
+            Cutoff::Triangle => Histogram::from_image(img.as_ref()).triangle_threshold(Channel::Luminance)
+        };
+
+        if let Some(treshold) = auto {
+            binarize(img, treshold as u16, |pixel| pixel.luminance() as u16);
+        }
+    }
+}
+
+fn binarize(img: &mut Box<dyn ImageFormat>, treshold: u16, color: impl Fn(&Pixel) -> u16) {
+    for i in 0..img.get_width() {
+        for j in 0..img.get_height() {
+            if let Some(pixel) = img.get_pixel(i, j) {
+                if color(pixel) > treshold {
+                    img.set_pixel(i, j, Colors::WHITE);
+                } else {
+                    img.set_pixel(i, j, Colors::BLACK);
                 }
             }
         }
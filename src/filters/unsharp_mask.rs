@@ -0,0 +1,56 @@
+use crate::formats::ImageFormat;
+use crate::utils::{calculate_gaussian_kernel_1d, separable_convolution};
+
+use super::histogram_equalization::process_planes;
+use super::ImageFilter;
+
+/// Sharpens by adding back the difference between the image and its blurred copy, keeps the overall brightness
+pub struct UnsharpMask {
+    amount: f32,
+    radius: f32,
+    threshold: u8
+}
+
+impl UnsharpMask {
+    /// * `amount` - strength of the sharpening, 1 doubles the local contrast
+    /// * `radius` - sigma of the Gaussian blur, the size of the sharpened details
+    /// * `threshold` - minimal difference from the blurred copy that gets sharpened, keeps noise and smooth areas untouched
+    /// 
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::UnsharpMask;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(UnsharpMask::new(1.5, 1.0, 3));
+    /// ```
+    pub fn new(amount: f32, radius: f32, threshold: u8) -> Self {
+        assert!(radius > 0.0, "Radius must be positive");
+
+        Self {
+            amount,
+            radius,
+            threshold
+        }
+    }
+}
+
+impl ImageFilter for UnsharpMask {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let size = 2 * (3.0 * self.radius).ceil() as u32 + 1;
+        let kernel = calculate_gaussian_kernel_1d(self.radius, size);
+
+        process_planes(img, false, |plane, width, height| {
+            let values: Vec<f32> = plane.iter().map(|&v| v as f32).collect();
+            let blurred = separable_convolution(&values, width, height, &kernel);
+
+            for (value, blur) in plane.iter_mut().zip(blurred) {
+                let diff = *value as f32 - blur;
+
+                if diff.abs() >= self.threshold as f32 {
+                    *value = (*value as f32 + diff * self.amount).round().clamp(0.0, 255.0) as u8;
+                }
+            }
+        });
+    }
+}
\ No newline at end of file
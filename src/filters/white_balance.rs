@@ -0,0 +1,163 @@
+use crate::analysis::Histogram;
+use crate::formats::ImageFormat;
+use crate::models::{Channel, Pixel};
+
+use super::ImageFilter;
+
+enum Method {
+    GrayWorld,
+    WhitePatch(f32),
+    Temperature(f32, f32),
+    Neutral(Pixel)
+}
+
+/// Removes color casts by scaling the color channels
+pub struct WhiteBalance {
+    method: Method
+}
+
+impl WhiteBalance {
+    /// Assumes the average color of the scene is gray
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::filters::WhiteBalance;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// img.apply_filter(WhiteBalance::gray_world());
+    /// ```
+    pub fn gray_world() -> Self {
+        Self {
+            method: Method::GrayWorld
+        }
+    }
+
+    /// Assumes the brightest part of the scene is white (max-RGB)
+    /// * `percentile` - channel percentile treated as white, 100 uses the maximum, lower values ignore specular highlights
+    pub fn white_patch(percentile: f32) -> Self {
+        Self {
+            method: Method::WhitePatch(percentile.clamp(0.0, 100.0))
+        }
+    }
+
+    /// Corrects for a light source of the given color temperature
+    /// * `kelvin` - temperature of the light the photo was taken under (e.g. 3200 for tungsten, 6500 for daylight)
+    /// * `tint` - green (-100) to magenta (100) shift
+    pub fn temperature(kelvin: f32, tint: f32) -> Self {
+        Self {
+            method: Method::Temperature(kelvin.clamp(1000.0, 40000.0), tint.clamp(-100.0, 100.0))
+        }
+    }
+
+    /// Makes the given color neutral gray, usually a pixel picked from something that should be white or gray
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::{formats::ImageFormat, Image};
+    /// use rusty_imager::filters::WhiteBalance;
+    /// 
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// let reference = img.get_pixel(10, 20).unwrap().clone();
+    /// img.apply_filter(WhiteBalance::neutral(reference));
+    /// ```
+    pub fn neutral(reference: Pixel) -> Self {
+        Self {
+            method: Method::Neutral(reference)
+        }
+    }
+
+    fn gains(&self, img: &dyn ImageFormat) -> Option<(f32, f32, f32)> {
+        match &self.method {
+            Method::GrayWorld => {
+                let hist = Histogram::from_image(img);
+                let r = hist.statistics(Channel::Red)?.mean;
+                let g = hist.statistics(Channel::Green)?.mean;
+                let b = hist.statistics(Channel::Blue)?.mean;
+
+                neutral_gains(r, g, b)
+            }
+
+            Method::WhitePatch(percentile) => {
+                let hist = Histogram::from_image(img);
+                let r = hist.percentile(Channel::Red, *percentile)?.max(1) as f32;
+                let g = hist.percentile(Channel::Green, *percentile)?.max(1) as f32;
+                let b = hist.percentile(Channel::Blue, *percentile)?.max(1) as f32;
+
+                Some((255.0 / r, 255.0 / g, 255.0 / b))
+            }
+
+            Method::Temperature(kelvin, tint) => {
+                let (wr, wg, wb) = kelvin_to_rgb(6500.0);
+                let (r, g, b) = kelvin_to_rgb(*kelvin);
+                let (gr, gg, gb) = (wr / r, wg / g * (1.0 - tint * 0.005), wb / b);
+
+                // Keep the overall brightness
+                let luma = 0.299 * gr + 0.587 * gg + 0.114 * gb;
+                Some((gr / luma, gg / luma, gb / luma))
+            }
+
+            Method::Neutral(reference) => {
+                neutral_gains(reference.r as f32, reference.g as f32, reference.b as f32)
+            }
+        }
+    }
+}
+
+impl ImageFilter for WhiteBalance {
+    fn apply(&self, img: &mut Box<dyn ImageFormat>) {
+        let Some((gr, gg, gb)) = self.gains(img.as_ref()) else {
+            return;
+        };
+
+        for i in 0..img.get_width() {
+            for j in 0..img.get_height() {
+                if let Some(pixel) = img.get_pixel(i, j) {
+                    let px = Pixel {
+                        r: (pixel.r as f32 * gr).round().clamp(0.0, 255.0) as u8,
+                        g: (pixel.g as f32 * gg).round().clamp(0.0, 255.0) as u8,
+                        b: (pixel.b as f32 * gb).round().clamp(0.0, 255.0) as u8
+                    };
+
+                    img.set_pixel(i, j, px);
+                }
+            }
+        }
+    }
+}
+
+/// Gains that turn the given color into a gray of the same average brightness
+fn neutral_gains(r: f32, g: f32, b: f32) -> Option<(f32, f32, f32)> {
+    if r <= 0.0 || g <= 0.0 || b <= 0.0 {
+        return None
+    }
+
+    let gray = (r + g + b) / 3.0;
+
+    Some((gray / r, gray / g, gray / b))
+}
+
+/// Approximates the color of a black body (Tanner Helland's fit)
+fn kelvin_to_rgb(kelvin: f32) -> (f32, f32, f32) {
+    let temp = kelvin / 100.0;
+
+    let r = if temp <= 66.0 {
+        255.0
+    } else {
+        329.69873 * (temp - 60.0).powf(-0.13320476)
+    };
+
+    let g = if temp <= 66.0 {
+        99.4708 * temp.ln() - 161.11957
+    } else {
+        288.12216 * (temp - 60.0).powf(-0.07551485)
+    };
+
+    let b = if temp >= 66.0 {
+        255.0
+    } else if temp <= 19.0 {
+        0.0
+    } else {
+        138.51773 * (temp - 10.0).ln() - 305.0448
+    };
+
+    (r.clamp(1.0, 255.0), g.clamp(1.0, 255.0), b.clamp(1.0, 255.0))
+}
\ No newline at end of file
@@ -48,7 +48,7 @@ pub struct Bmp {
  
 impl ImageFormat for Bmp {
     fn get_pixel(&self, x: usize, y: usize) -> Option<&Pixel> {
-        if x > self.info.width as usize - 1 || y > self.info.height as usize - 1 {
+        if x >= self.info.width as usize || y >= self.info.height as usize {
             return None
         }
 
@@ -56,7 +56,7 @@ impl ImageFormat for Bmp {
     }
 
     fn set_pixel(&mut self, x: usize, y: usize, pixel: Pixel) -> Option<()> {
-        if x > self.info.width as usize - 1 || y > self.info.height as usize - 1 {
+        if x >= self.info.width as usize || y >= self.info.height as usize {
             return None
         }
 
@@ -114,7 +114,7 @@ impl ImageFormat for Bmp {
 
             res
         }
-        fn convert_data(data: &Vec<Pixel>) -> Vec<u8> {
+        fn convert_data(data: &[Pixel]) -> Vec<u8> {
             data.iter().flat_map(|p| p.to_bytes(true)).collect()
         }
 
@@ -129,6 +129,39 @@ impl ImageFormat for Bmp {
 }
 
 impl Bmp {
+    /// Creates a black 24-bit image with the given dimensions
+    pub fn new(width: usize, height: usize) -> Self {
+        let image_size = (width * height * 3) as u32;
+
+        let header = BmpHeader {
+            signature: [0x42, 0x4D],
+            file_size: 54 + image_size,
+            reserved: 0,
+            data_offset: 54
+        };
+
+        let info = BmpInfo {
+            size: 40,
+            width: width as u32,
+            height: height as u32,
+            planes: 1,
+            bits_per_pixel: 24,
+            compression: 0,
+            image_size,
+            h_res: 2835,
+            v_res: 2835,
+            colors: 0,
+            important_colors: 0,
+            padding: vec![]
+        };
+
+        Bmp {
+            header,
+            info,
+            colors: None,
+            data: vec![Pixel::default(); width * height]
+        }
+    }
 
     // TODO: Switch from Option<Self> to Result<Self, ImageFormatError>
     pub fn parse(data: &[u8]) -> Option<Self> {
@@ -160,7 +193,7 @@ impl Bmp {
         Some(BmpHeader {
             signature: signature.try_into().unwrap(),
             file_size: size,
-            reserved: reserved,
+            reserved,
             data_offset: offset
         })
     }
@@ -195,7 +228,7 @@ impl Bmp {
             v_res: y_pixels,
             colors: colors_used,
             important_colors: colors_important,
-            padding: padding
+            padding
         };
 
         Some(info)
@@ -0,0 +1,126 @@
+use std::f32::consts::TAU;
+
+use crate::formats::ImageFormat;
+use crate::models::{Pixel, Point};
+use crate::Image;
+
+/// Geometry mapping a position to the gradient offset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientShape {
+    /// Offset 0 at `start` and 1 at `end`, constant along lines perpendicular to them
+    Linear { start: Point, end: Point },
+    /// Offset grows with the distance from the center, 1 at `radius`
+    Radial { center: Point, radius: f32 },
+    /// Offset grows clockwise around the center, starting at `angle` (radians, 0 points right)
+    Conic { center: Point, angle: f32 }
+}
+
+/// Smooth transition between colors placed at offsets (0 - 1)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    shape: GradientShape,
+    stops: Vec<(f32, Pixel)>
+}
+
+impl Gradient {
+    /// Creates a gradient, colors before the first and after the last stop are extended
+    /// * `stops` - offsets and their colors in any order
+    pub fn new(shape: GradientShape, stops: &[(f32, Pixel)]) -> Self {
+        assert!(!stops.is_empty(), "Gradient needs at least one color stop");
+
+        let mut stops = stops.to_vec();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self { shape, stops }
+    }
+
+    /// Linear gradient between two points
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::generators::Gradient;
+    /// use rusty_imager::models::{Colors, Point};
+    ///
+    /// let sunset = Gradient::linear(
+    ///     Point::new(0.0, 0.0),
+    ///     Point::new(0.0, 479.0),
+    ///     &[(0.0, Colors::MIDNIGHT_BLUE), (0.6, Colors::ORANGE_RED), (1.0, Colors::GOLD)]
+    /// );
+    ///
+    /// sunset.render(640, 480).save("<name>.bmp").expect("Failed to save the image");
+    /// ```
+    pub fn linear(start: Point, end: Point, stops: &[(f32, Pixel)]) -> Self {
+        Self::new(GradientShape::Linear { start, end }, stops)
+    }
+
+    pub fn radial(center: Point, radius: f32, stops: &[(f32, Pixel)]) -> Self {
+        assert!(radius > 0.0, "Radius must be positive");
+
+        Self::new(GradientShape::Radial { center, radius }, stops)
+    }
+
+    pub fn conic(center: Point, angle: f32, stops: &[(f32, Pixel)]) -> Self {
+        Self::new(GradientShape::Conic { center, angle }, stops)
+    }
+
+    /// Color at the offset, interpolated between the surrounding stops
+    pub fn color_at(&self, offset: f32) -> Pixel {
+        let first = &self.stops[0];
+        let last = &self.stops[self.stops.len() - 1];
+
+        if offset <= first.0 {
+            return first.1.clone();
+        }
+
+        if offset >= last.0 {
+            return last.1.clone();
+        }
+
+        let next = self.stops.iter().position(|s| s.0 > offset).unwrap_or(self.stops.len() - 1);
+        let (o0, c0) = &self.stops[next - 1];
+        let (o1, c1) = &self.stops[next];
+        let t = (offset - o0) / (o1 - o0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+
+        Pixel {
+            r: mix(c0.r, c1.r),
+            g: mix(c0.g, c1.g),
+            b: mix(c0.b, c1.b)
+        }
+    }
+
+    /// Gradient offset at the position, not clamped
+    pub fn offset_at(&self, position: Point) -> f32 {
+        match self.shape {
+            GradientShape::Linear { start, end } => {
+                let (dx, dy) = (end.x - start.x, end.y - start.y);
+                let length_squared = dx * dx + dy * dy;
+                if length_squared == 0.0 {
+                    return 0.0;
+                }
+
+                ((position.x - start.x) * dx + (position.y - start.y) * dy) / length_squared
+            }
+
+            GradientShape::Radial { center, radius } => position.distance(&center) / radius,
+
+            GradientShape::Conic { center, angle } => {
+                let theta = (position.y - center.y).atan2(position.x - center.x);
+                ((theta - angle) / TAU).rem_euclid(1.0)
+            }
+        }
+    }
+
+    /// Creates an image filled with the gradient, pixels are sampled at their integer coordinates
+    pub fn render(&self, width: usize, height: usize) -> Image {
+        let mut res = Image::new(width, height);
+
+        for y in 0..height {
+            for x in 0..width {
+                let offset = self.offset_at(Point::new(x as f32, y as f32));
+                res.set_pixel(x, y, self.color_at(offset));
+            }
+        }
+
+        res
+    }
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+mod gradient;
+mod pattern;
+mod noise;
+
+pub use gradient::{Gradient, GradientShape};
+pub use pattern::checkerboard;
+pub use noise::Noise;
\ No newline at end of file
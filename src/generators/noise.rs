@@ -0,0 +1,169 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+use crate::utils::Random;
+use crate::Image;
+
+/// Skew factors between the square grid and the simplex (triangle) grid
+const F2: f32 = 0.366_025_4;
+const G2: f32 = 0.211_324_87;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NoiseKind {
+    Perlin,
+    Simplex
+}
+
+/// Smooth 2D gradient noise, the same seed always gives the same values
+#[derive(Debug, Clone)]
+pub struct Noise {
+    kind: NoiseKind,
+    permutation: Vec<u8>
+}
+
+impl Noise {
+    /// Classic (improved) Perlin noise on a square grid
+    pub fn perlin(seed: u64) -> Self {
+        Self::new(NoiseKind::Perlin, seed)
+    }
+
+    /// Simplex noise on a triangle grid, fewer directional artefacts than Perlin noise
+    pub fn simplex(seed: u64) -> Self {
+        Self::new(NoiseKind::Simplex, seed)
+    }
+
+    fn new(kind: NoiseKind, seed: u64) -> Self {
+        let mut random = Random::new(seed);
+        let mut permutation: Vec<u8> = (0..=255).collect();
+
+        for i in (1..256).rev() {
+            permutation.swap(i, random.below(i + 1));
+        }
+
+        // Doubled so the hashes of neighbouring cells do not need wrapping
+        permutation.extend_from_within(..);
+
+        Self { kind, permutation }
+    }
+
+    /// Noise value (about -1 - 1) at the position, features are roughly one unit apart
+    pub fn sample(&self, x: f32, y: f32) -> f32 {
+        match self.kind {
+            NoiseKind::Perlin => self.perlin_sample(x, y),
+            NoiseKind::Simplex => self.simplex_sample(x, y)
+        }
+    }
+
+    /// Sum of octaves with doubling frequency (fractal Brownian motion), normalized to about -1 - 1
+    /// * `persistence` - amplitude ratio of two successive octaves, usually 0.5
+    pub fn fractal(&self, x: f32, y: f32, octaves: u32, persistence: f32) -> f32 {
+        let mut sum = 0f32;
+        let mut amplitude = 1f32;
+        let mut total = 0f32;
+        let mut frequency = 1f32;
+
+        for _ in 0..octaves.max(1) {
+            sum += amplitude * self.sample(x * frequency, y * frequency);
+            total += amplitude;
+            amplitude *= persistence;
+            frequency *= 2.0;
+        }
+
+        sum / total
+    }
+
+    /// Creates a grayscale image of fractal noise
+    /// * `scale` - size of the largest features in pixels
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::generators::Noise;
+    ///
+    /// let clouds = Noise::perlin(42).render(512, 512, 128.0, 5);
+    /// clouds.save("<name>.bmp").expect("Failed to save the image");
+    /// ```
+    pub fn render(&self, width: usize, height: usize, scale: f32, octaves: u32) -> Image {
+        assert!(scale > 0.0, "Scale must be positive");
+
+        let mut res = Image::new(width, height);
+
+        for y in 0..height {
+            for x in 0..width {
+                let value = self.fractal(x as f32 / scale, y as f32 / scale, octaves, 0.5);
+                let v = ((value + 1.0) * 127.5).round().clamp(0.0, 255.0) as u8;
+                res.set_pixel(x, y, Pixel { r: v, g: v, b: v });
+            }
+        }
+
+        res
+    }
+
+    fn hash(&self, x: i32, y: i32) -> u8 {
+        let p = &self.permutation;
+        p[p[(x & 255) as usize] as usize + (y & 255) as usize]
+    }
+
+    fn perlin_sample(&self, x: f32, y: f32) -> f32 {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (xf, yf) = (x - x0, y - y0);
+        let (xi, yi) = (x0 as i32, y0 as i32);
+
+        let n00 = gradient(self.hash(xi, yi), xf, yf);
+        let n10 = gradient(self.hash(xi + 1, yi), xf - 1.0, yf);
+        let n01 = gradient(self.hash(xi, yi + 1), xf, yf - 1.0);
+        let n11 = gradient(self.hash(xi + 1, yi + 1), xf - 1.0, yf - 1.0);
+
+        let (u, v) = (fade(xf), fade(yf));
+        let top = n00 + u * (n10 - n00);
+        let bottom = n01 + u * (n11 - n01);
+
+        top + v * (bottom - top)
+    }
+
+    fn simplex_sample(&self, x: f32, y: f32) -> f32 {
+        // Cell of the skewed grid and the position inside of its first triangle corner
+        let s = (x + y) * F2;
+        let (i, j) = ((x + s).floor(), (y + s).floor());
+        let t = (i + j) * G2;
+        let (x0, y0) = (x - (i - t), y - (j - t));
+
+        // Lower or upper triangle of the cell
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+
+        let corners = [
+            (0, 0, x0, y0),
+            (i1, j1, x0 - i1 as f32 + G2, y0 - j1 as f32 + G2),
+            (1, 1, x0 - 1.0 + 2.0 * G2, y0 - 1.0 + 2.0 * G2)
+        ];
+
+        let (i, j) = (i as i32, j as i32);
+        let sum: f32 = corners.iter().map(|&(di, dj, dx, dy)| {
+            let falloff = 0.5 - dx * dx - dy * dy;
+            if falloff <= 0.0 {
+                return 0.0;
+            }
+
+            falloff.powi(4) * gradient(self.hash(i + di, j + dj), dx, dy)
+        }).sum();
+
+        // Scales the sum of the corner contributions to about -1 - 1
+        70.0 * sum
+    }
+}
+
+/// Dot product of the offset with one of eight gradient directions
+fn gradient(hash: u8, x: f32, y: f32) -> f32 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y
+    }
+}
+
+/// Quintic smoothstep, its first and second derivatives are zero at the grid points
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+use crate::Image;
+
+/// Creates a checkerboard of square cells, the top left cell has the first color
+/// # Example
+/// ```no_run
+/// use rusty_imager::generators::checkerboard;
+/// use rusty_imager::models::Colors;
+///
+/// let board = checkerboard(256, 256, 32, Colors::WHITE, Colors::SILVER);
+/// board.save("<name>.bmp").expect("Failed to save the image");
+/// ```
+pub fn checkerboard(width: usize, height: usize, cell_size: usize, first: Pixel, second: Pixel) -> Image {
+    assert!(cell_size > 0, "Cell size must be positive");
+
+    let mut res = Image::new(width, height);
+
+    for y in 0..height {
+        for x in 0..width {
+            let color = if (x / cell_size + y / cell_size).is_multiple_of(2) { &first } else { &second };
+            res.set_pixel(x, y, color.clone());
+        }
+    }
+
+    res
+}
\ No newline at end of file
@@ -3,12 +3,28 @@ use std::{fs, path::Path};
 use crate::filters::ImageFilter;
 use crate::formats::{bmp::Bmp, ImageFormat};
 use crate::models::{ImageType, Pixel};
+use crate::utils::resample_plane;
+use crate::Mask;
 
 pub struct Image {
     raw: Box<dyn ImageFormat>
 }
 
 impl Image {
+    /// Creates a black image with the given dimensions
+    /// # Example
+    /// ```
+    /// use rusty_imager::{formats::ImageFormat, Image};
+    /// 
+    /// let img = Image::new(640, 480);
+    /// assert_eq!(img.get_width(), 640);
+    /// ```
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            raw: Box::new(Bmp::new(width, height))
+        }
+    }
+
     /// Loads the image from specified path
     /// # Example
     /// ```no_run
@@ -70,6 +86,98 @@ impl Image {
         filter.apply(&mut self.raw);
     }
 
+    /// Applies a filter only where the mask selects the image
+    ///
+    /// Every pixel is mixed from the filtered and the original pixel by the mask weight, so feathered masks give smooth transitions
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::{Image, Mask};
+    /// use rusty_imager::filters::GaussianBlur;
+    /// use rusty_imager::drawing::{magic_wand, ColorTolerance};
+    /// use rusty_imager::formats::ImageFormat;
+    /// use rusty_imager::models::Connectivity;
+    ///
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// let backdrop = magic_wand(&img, 0, 0, ColorTolerance::Lab(12.0), Connectivity::Four);
+    /// let mask = Mask::from_selection(&backdrop, img.get_width(), img.get_height()).feather(2.0);
+    ///
+    /// img.apply_filter_masked(GaussianBlur::new(3.0, 19), &mask);
+    /// ```
+    pub fn apply_filter_masked(&mut self, filter: impl ImageFilter, mask: &Mask) {
+        let width = self.get_width();
+        let height = self.get_height();
+        assert!(
+            mask.get_width() == width && mask.get_height() == height,
+            "Mask must have the same dimensions as the image"
+        );
+
+        let mut original = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                original.push(self.get_pixel(x, y).cloned().unwrap_or_default());
+            }
+        }
+
+        filter.apply(&mut self.raw);
+
+        for (i, (before, &weight)) in original.iter().zip(mask.values()).enumerate() {
+            let (x, y) = (i % width, i / width);
+            let Some(after) = self.get_pixel(x, y) else {
+                continue;
+            };
+
+            let mix = |old: u8, new: u8| (old as f32 * (1.0 - weight) + new as f32 * weight).round() as u8;
+            let mixed = Pixel {
+                r: mix(before.r, after.r),
+                g: mix(before.g, after.g),
+                b: mix(before.b, after.b)
+            };
+
+            self.set_pixel(x, y, mixed);
+        }
+    }
+
+    /// Creates a copy of the image scaled to the given dimensions
+    ///
+    /// Every new pixel is the area weighted average of the pixels it covers, so shrinking does not alias
+    /// # Example
+    /// ```
+    /// use rusty_imager::{formats::ImageFormat, Image};
+    /// 
+    /// let img = Image::new(640, 480);
+    /// let thumbnail = img.resize(160, 120);
+    /// assert_eq!(thumbnail.get_width(), 160);
+    /// ```
+    pub fn resize(&self, width: usize, height: usize) -> Image {
+        assert!(width > 0 && height > 0, "Dimensions must be positive");
+
+        let src_width = self.get_width();
+        let src_height = self.get_height();
+        let mut res = Image::new(width, height);
+
+        if src_width == 0 || src_height == 0 {
+            return res;
+        }
+
+        let mut channels = [vec![], vec![], vec![]];
+        for y in 0..src_height {
+            for x in 0..src_width {
+                let pixel = self.get_pixel(x, y).cloned().unwrap_or_default();
+                channels[0].push(pixel.r as f32);
+                channels[1].push(pixel.g as f32);
+                channels[2].push(pixel.b as f32);
+            }
+        }
+
+        let [r, g, b] = channels.map(|c| resample_plane(&c, src_width, src_height, width, height));
+        for idx in 0..width * height {
+            let value = |c: &[f32]| c[idx].round().clamp(0.0, 255.0) as u8;
+            res.set_pixel(idx % width, idx / width, Pixel { r: value(&r), g: value(&g), b: value(&b) });
+        }
+
+        res
+    }
+
     /// Saves the loaded image
     /// # Example
     /// ```no_run
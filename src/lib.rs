@@ -3,5 +3,11 @@ pub mod image;
 pub mod models;
 pub mod filters;
 pub mod utils;
+pub mod analysis;
+pub mod quantization;
+pub mod drawing;
+pub mod mask;
+pub mod generators;
 
-pub use image::Image;
\ No newline at end of file
+pub use image::Image;
+pub use mask::Mask;
\ No newline at end of file
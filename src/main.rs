@@ -1,7 +1,7 @@
 use rusty_imager::Image;
 
-fn main() -> () {
-    let mut img = Image::from_file("data/reference.bmp").unwrap();
+fn main() {
+    let img = Image::from_file("data/reference.bmp").unwrap();
 
     img.save("test.bmp").unwrap();
 }
\ No newline at end of file
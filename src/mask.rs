@@ -0,0 +1,161 @@
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+use crate::utils::{calculate_gaussian_kernel_1d, luminance_plane, separable_convolution};
+use crate::Image;
+
+/// Grayscale weight (0 - 1) of every pixel, selects where and how strongly an operation applies
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mask {
+    width: usize,
+    height: usize,
+    values: Vec<f32>
+}
+
+impl Mask {
+    /// Creates an empty mask, nothing is selected
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, 0.0)
+    }
+
+    /// Creates a mask with the same weight everywhere
+    pub fn filled(width: usize, height: usize, value: f32) -> Self {
+        Self {
+            width,
+            height,
+            values: vec![value.clamp(0.0, 1.0); width * height]
+        }
+    }
+
+    /// Creates a mask from weights stored row by row
+    pub fn from_values(values: Vec<f32>, width: usize, height: usize) -> Self {
+        assert_eq!(values.len(), width * height, "Values must match the dimensions");
+
+        Self {
+            width,
+            height,
+            values: values.into_iter().map(|v| v.clamp(0.0, 1.0)).collect()
+        }
+    }
+
+    /// Creates a mask from flags stored row by row, e.g. the output of `magic_wand`
+    pub fn from_selection(selection: &[bool], width: usize, height: usize) -> Self {
+        Self::from_values(selection.iter().map(|&s| s as u8 as f32).collect(), width, height)
+    }
+
+    /// Uses the luminance of the image as the weight, white is fully selected
+    pub fn from_image(img: &dyn ImageFormat) -> Self {
+        let values = luminance_plane(img).iter().map(|&v| v as f32 / 255.0).collect();
+
+        Self::from_values(values, img.get_width(), img.get_height())
+    }
+
+    /// Selects the pixels with luminance in the inclusive range
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::{Image, Mask};
+    /// use rusty_imager::filters::Brightness;
+    ///
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// let shadows = Mask::from_threshold(&img, 0, 70).feather(4.0);
+    ///
+    /// img.apply_filter_masked(Brightness::new(40), &shadows);
+    /// ```
+    pub fn from_threshold(img: &dyn ImageFormat, low: u8, high: u8) -> Self {
+        let values = luminance_plane(img).iter().map(|v| (low..=high).contains(v) as u8 as f32).collect();
+
+        Self::from_values(values, img.get_width(), img.get_height())
+    }
+
+    /// Creates a mask from shapes drawn in white on a black canvas
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::{Image, Mask};
+    /// use rusty_imager::drawing::fill_ellipse;
+    /// use rusty_imager::filters::GaussianBlur;
+    /// use rusty_imager::formats::ImageFormat;
+    /// use rusty_imager::models::{Colors, Point};
+    ///
+    /// let mut img = Image::from_file("<path>").expect("File not found!");
+    /// let (width, height) = (img.get_width(), img.get_height());
+    ///
+    /// // Blurs everything but the subject in the middle
+    /// let subject = Mask::from_shapes(width, height, |canvas| {
+    ///     let center = Point::new(width as f32 / 2.0, height as f32 / 2.0);
+    ///     fill_ellipse(canvas, center, width as f32 / 4.0, height as f32 / 3.0, Colors::WHITE);
+    /// });
+    ///
+    /// img.apply_filter_masked(GaussianBlur::new(4.0, 25), &subject.feather(8.0).invert());
+    /// ```
+    pub fn from_shapes(width: usize, height: usize, draw: impl FnOnce(&mut dyn ImageFormat)) -> Self {
+        let mut canvas = Image::new(width, height);
+        draw(&mut canvas);
+
+        Self::from_image(&canvas)
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Weights stored row by row
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<f32> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        Some(self.values[y * self.width + x])
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: f32) -> Option<()> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.values[y * self.width + x] = value.clamp(0.0, 1.0);
+        Some(())
+    }
+
+    /// Swaps the selected and unselected parts
+    pub fn invert(&self) -> Self {
+        Self {
+            width: self.width,
+            height: self.height,
+            values: self.values.iter().map(|v| 1.0 - v).collect()
+        }
+    }
+
+    /// Softens the edges of the mask with a Gaussian blur
+    /// * `radius` - standard deviation of the blur in pixels
+    pub fn feather(&self, radius: f32) -> Self {
+        assert!(radius >= 0.0, "Radius must not be negative");
+
+        if radius == 0.0 {
+            return self.clone();
+        }
+
+        let size = 2 * (3.0 * radius).ceil() as u32 + 1;
+        let kernel = calculate_gaussian_kernel_1d(radius, size);
+
+        Self::from_values(separable_convolution(&self.values, self.width, self.height, &kernel), self.width, self.height)
+    }
+
+    /// Draws the mask as a grayscale image
+    pub fn to_image(&self) -> Image {
+        let mut res = Image::new(self.width, self.height);
+
+        for (i, value) in self.values.iter().enumerate() {
+            let v = (value * 255.0).round() as u8;
+            res.set_pixel(i % self.width, i / self.width, Pixel { r: v, g: v, b: v });
+        }
+
+        res
+    }
+}
\ No newline at end of file
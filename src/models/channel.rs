@@ -0,0 +1,22 @@
+use super::Pixel;
+
+/// Color channel of a pixel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+    Luminance
+}
+
+impl Channel {
+    /// Extracts the channel value from the pixel
+    pub fn value(&self, pixel: &Pixel) -> u8 {
+        match self {
+            Channel::Red => pixel.r,
+            Channel::Green => pixel.g,
+            Channel::Blue => pixel.b,
+            Channel::Luminance => pixel.luminance()
+        }
+    }
+}
\ No newline at end of file
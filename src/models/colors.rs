@@ -84,10 +84,12 @@ impl Colors {
     pub const DARK_ORCHID: Pixel = Pixel { r: 153, g: 50, b: 204};
     pub const MEDIUM_ORCHID: Pixel = Pixel { r: 186, g: 85, b: 211};
     pub const PURPLE: Pixel = Pixel { r: 128, g: 0, b: 128};
+    pub const REBECCA_PURPLE: Pixel = Pixel { r: 102, g: 51, b: 153};
     pub const THISTLE: Pixel = Pixel { r: 216, g: 191, b: 216};
     pub const PLUM: Pixel = Pixel { r: 221, g: 160, b: 221};
     pub const VIOLET: Pixel = Pixel { r: 238, g: 130, b: 238};
     pub const MAGENTA: Pixel = Pixel { r: 255, g: 0, b: 255};
+    pub const FUCHSIA: Pixel = Pixel { r: 255, g: 0, b: 255};
     pub const ORCHID: Pixel = Pixel { r: 218, g: 112, b: 214};
     pub const MEDIUM_VIOLET_RED: Pixel = Pixel { r: 199, g: 21, b: 133};
     pub const PALE_VIOLET_RED: Pixel = Pixel { r: 219, g: 112, b: 147};
@@ -142,4 +144,187 @@ impl Colors {
     pub const GAINSBORO: Pixel = Pixel { r: 220, g: 220, b: 220};
     pub const WHITE_SMOKE: Pixel = Pixel { r: 245, g: 245, b: 245};
     pub const WHITE: Pixel = Pixel { r: 255, g: 255, b: 255};
+
+    /// All named colors with their lowercase CSS names
+    pub const NAMED: [(&'static str, Pixel); 141] = [
+        ("maroon", Colors::MAROON),
+        ("darkred", Colors::DARK_RED),
+        ("brown", Colors::BROWN),
+        ("firebrick", Colors::FIREBRICK),
+        ("crimson", Colors::CRIMSON),
+        ("red", Colors::RED),
+        ("tomato", Colors::TOMATO),
+        ("coral", Colors::CORAL),
+        ("indianred", Colors::INDIAN_RED),
+        ("lightcoral", Colors::LIGHT_CORAL),
+        ("darksalmon", Colors::DARK_SALMON),
+        ("salmon", Colors::SALMON),
+        ("lightsalmon", Colors::LIGHT_SALMON),
+        ("orangered", Colors::ORANGE_RED),
+        ("darkorange", Colors::DARK_ORANGE),
+        ("orange", Colors::ORANGE),
+        ("gold", Colors::GOLD),
+        ("darkgoldenrod", Colors::DARK_GOLDEN_ROD),
+        ("goldenrod", Colors::GOLDEN_ROD),
+        ("palegoldenrod", Colors::PALE_GOLDEN_ROD),
+        ("darkkhaki", Colors::DARK_KHAKI),
+        ("khaki", Colors::KHAKI),
+        ("olive", Colors::OLIVE),
+        ("yellow", Colors::YELLOW),
+        ("yellowgreen", Colors::YELLOW_GREEN),
+        ("darkolivegreen", Colors::DARK_OLIVE_GREEN),
+        ("olivedrab", Colors::OLIVE_DRAB),
+        ("lawngreen", Colors::LAWN_GREEN),
+        ("chartreuse", Colors::CHARTREUSE),
+        ("greenyellow", Colors::GREEN_YELLOW),
+        ("darkgreen", Colors::DARK_GREEN),
+        ("green", Colors::GREEN),
+        ("forestgreen", Colors::FOREST_GREEN),
+        ("lime", Colors::LIME),
+        ("limegreen", Colors::LIME_GREEN),
+        ("lightgreen", Colors::LIGHT_GREEN),
+        ("palegreen", Colors::PALE_GREEN),
+        ("darkseagreen", Colors::DARK_SEA_GREEN),
+        ("mediumspringgreen", Colors::MEDIUM_SPRING_GREEN),
+        ("springgreen", Colors::SPRING_GREEN),
+        ("seagreen", Colors::SEA_GREEN),
+        ("mediumaquamarine", Colors::MEDIUM_AQUA_MARINE),
+        ("mediumseagreen", Colors::MEDIUM_SEA_GREEN),
+        ("lightseagreen", Colors::LIGHT_SEA_GREEN),
+        ("darkslategray", Colors::DARK_SLATE_GRAY),
+        ("teal", Colors::TEAL),
+        ("darkcyan", Colors::DARK_CYAN),
+        ("aqua", Colors::AQUA),
+        ("cyan", Colors::CYAN),
+        ("lightcyan", Colors::LIGHT_CYAN),
+        ("darkturquoise", Colors::DARK_TURQUOISE),
+        ("turquoise", Colors::TURQUOISE),
+        ("mediumturquoise", Colors::MEDIUM_TURQUOISE),
+        ("paleturquoise", Colors::PALE_TURQUOISE),
+        ("aquamarine", Colors::AQUA_MARINE),
+        ("powderblue", Colors::POWDER_BLUE),
+        ("cadetblue", Colors::CADET_BLUE),
+        ("steelblue", Colors::STEEL_BLUE),
+        ("cornflowerblue", Colors::CORN_FLOWER_BLUE),
+        ("deepskyblue", Colors::DEEP_SKY_BLUE),
+        ("dodgerblue", Colors::DODGER_BLUE),
+        ("lightblue", Colors::LIGHT_BLUE),
+        ("skyblue", Colors::SKY_BLUE),
+        ("lightskyblue", Colors::LIGHT_SKY_BLUE),
+        ("midnightblue", Colors::MIDNIGHT_BLUE),
+        ("navy", Colors::NAVY),
+        ("darkblue", Colors::DARK_BLUE),
+        ("mediumblue", Colors::MEDIUM_BLUE),
+        ("blue", Colors::BLUE),
+        ("royalblue", Colors::ROYAL_BLUE),
+        ("blueviolet", Colors::BLUE_VIOLET),
+        ("indigo", Colors::INDIGO),
+        ("darkslateblue", Colors::DARK_SLATE_BLUE),
+        ("slateblue", Colors::SLATE_BLUE),
+        ("mediumslateblue", Colors::MEDIUM_SLATE_BLUE),
+        ("mediumpurple", Colors::MEDIUM_PURPLE),
+        ("darkmagenta", Colors::DARK_MAGENTA),
+        ("darkviolet", Colors::DARK_VIOLET),
+        ("darkorchid", Colors::DARK_ORCHID),
+        ("mediumorchid", Colors::MEDIUM_ORCHID),
+        ("purple", Colors::PURPLE),
+        ("rebeccapurple", Colors::REBECCA_PURPLE),
+        ("thistle", Colors::THISTLE),
+        ("plum", Colors::PLUM),
+        ("violet", Colors::VIOLET),
+        ("magenta", Colors::MAGENTA),
+        ("fuchsia", Colors::FUCHSIA),
+        ("orchid", Colors::ORCHID),
+        ("mediumvioletred", Colors::MEDIUM_VIOLET_RED),
+        ("palevioletred", Colors::PALE_VIOLET_RED),
+        ("deeppink", Colors::DEEP_PINK),
+        ("hotpink", Colors::HOT_PINK),
+        ("lightpink", Colors::LIGHT_PINK),
+        ("pink", Colors::PINK),
+        ("antiquewhite", Colors::ANTIQUE_WHITE),
+        ("beige", Colors::BEIGE),
+        ("bisque", Colors::BISQUE),
+        ("blanchedalmond", Colors::BLANCHED_ALMOND),
+        ("wheat", Colors::WHEAT),
+        ("cornsilk", Colors::CORN_SILK),
+        ("lemonchiffon", Colors::LEMON_CHIFFON),
+        ("lightgoldenrodyellow", Colors::LIGHT_GOLDEN_ROD_YELLOW),
+        ("lightyellow", Colors::LIGHT_YELLOW),
+        ("saddlebrown", Colors::SADDLE_BROWN),
+        ("sienna", Colors::SIENNA),
+        ("chocolate", Colors::CHOCOLATE),
+        ("peru", Colors::PERU),
+        ("sandybrown", Colors::SANDY_BROWN),
+        ("burlywood", Colors::BURLY_WOOD),
+        ("tan", Colors::TAN),
+        ("rosybrown", Colors::ROSY_BROWN),
+        ("moccasin", Colors::MOCCASIN),
+        ("navajowhite", Colors::NAVAJO_WHITE),
+        ("peachpuff", Colors::PEACH_PUFF),
+        ("mistyrose", Colors::MISTY_ROSE),
+        ("lavenderblush", Colors::LAVENDER_BLUSH),
+        ("linen", Colors::LINEN),
+        ("oldlace", Colors::OLD_LACE),
+        ("papayawhip", Colors::PAPAYA_WHIP),
+        ("seashell", Colors::SEA_SHELL),
+        ("mintcream", Colors::MINT_CREAM),
+        ("slategray", Colors::SLATE_GRAY),
+        ("lightslategray", Colors::LIGHT_SLATE_GRAY),
+        ("lightsteelblue", Colors::LIGHT_STEEL_BLUE),
+        ("lavender", Colors::LAVENDER),
+        ("floralwhite", Colors::FLORAL_WHITE),
+        ("aliceblue", Colors::ALICE_BLUE),
+        ("ghostwhite", Colors::GHOST_WHITE),
+        ("honeydew", Colors::HONEYDEW),
+        ("ivory", Colors::IVORY),
+        ("azure", Colors::AZURE),
+        ("snow", Colors::SNOW),
+        ("black", Colors::BLACK),
+        ("dimgray", Colors::DIM_GRAY),
+        ("gray", Colors::GRAY),
+        ("darkgray", Colors::DARK_GRAY),
+        ("silver", Colors::SILVER),
+        ("lightgray", Colors::LIGHT_GRAY),
+        ("gainsboro", Colors::GAINSBORO),
+        ("whitesmoke", Colors::WHITE_SMOKE),
+        ("white", Colors::WHITE),
+    ];
+
+    /// Looks up a color by its CSS name, case insensitive, "grey" spellings are accepted
+    /// # Example
+    /// ```
+    /// use rusty_imager::models::Colors;
+    /// 
+    /// assert_eq!(Colors::by_name("CornflowerBlue"), Some(Colors::CORN_FLOWER_BLUE));
+    /// ```
+    pub fn by_name(name: &str) -> Option<Pixel> {
+        let name = name.trim().to_ascii_lowercase().replace("grey", "gray");
+
+        Colors::NAMED.iter()
+            .find(|(named, _)| *named == name)
+            .map(|(_, color)| color.clone())
+    }
+
+    /// Gets the CSS name of the color, if it's exactly one of the named colors
+    pub fn name_of(pixel: &Pixel) -> Option<&'static str> {
+        Colors::NAMED.iter()
+            .find(|(_, color)| color == pixel)
+            .map(|(name, _)| *name)
+    }
+
+    /// Finds the named color perceptually closest to the given pixel (CIE76 distance in Lab space)
+    /// # Example
+    /// ```
+    /// use rusty_imager::models::{Colors, Pixel};
+    /// 
+    /// let (name, _) = Colors::nearest(&Pixel { r: 250, g: 5, b: 3 });
+    /// assert_eq!(name, "red");
+    /// ```
+    pub fn nearest(pixel: &Pixel) -> (&'static str, Pixel) {
+        let (name, color) = Colors::NAMED.iter()
+            .min_by(|(_, a), (_, b)| pixel.lab_distance(a).total_cmp(&pixel.lab_distance(b)))
+            .unwrap();
+
+        (name, color.clone())
+    }
 }
\ No newline at end of file
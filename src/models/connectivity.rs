@@ -0,0 +1,18 @@
+/// Which neighbours of a pixel are considered connected to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Left, right, top and bottom neighbours
+    Four,
+    /// Diagonal neighbours as well
+    Eight
+}
+
+impl Connectivity {
+    /// Offsets of the connected neighbours
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(1, 0), (-1, 0), (0, 1), (0, -1)],
+            Connectivity::Eight => &[(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (-1, 1), (1, -1), (-1, -1)]
+        }
+    }
+}
\ No newline at end of file
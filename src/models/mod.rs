@@ -1,7 +1,15 @@
 mod pixel;
 mod image_type;
 mod colors;
+mod channel;
+mod rect;
+mod connectivity;
+mod point;
 
 pub use pixel::Pixel;
 pub use image_type::ImageType;
-pub use colors::Colors;
\ No newline at end of file
+pub use colors::Colors;
+pub use channel::Channel;
+pub use rect::Rect;
+pub use connectivity::Connectivity;
+pub use point::Point;
\ No newline at end of file
@@ -1,3 +1,5 @@
+use super::Colors;
+
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
 pub struct Pixel {
@@ -7,22 +9,122 @@ pub struct Pixel {
 }
 
 impl Pixel {
+    /// Parses a hex color in `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` format, the alpha is ignored
     pub fn from_hex(hex: &str) -> Option<Self> {
-        if !hex.starts_with("#") || hex.len() != 7 {
+        let digits = hex.strip_prefix("#")?;
+
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
             return None
         }
 
-        let r = u8::from_str_radix(hex.get(1..3).unwrap_or_default(), 16).ok()?;
-        let g = u8::from_str_radix(hex.get(3..5).unwrap_or_default(), 16).ok()?;
-        let b = u8::from_str_radix(hex.get(5..7).unwrap_or_default(), 16).ok()?;
+        let channel = |idx: usize, len: usize| {
+            let value = u8::from_str_radix(digits.get(idx * len..(idx + 1) * len)?, 16).ok()?;
+
+            // Short form repeats the digit (#f80 is #ff8800)
+            Some(if len == 1 { value * 17 } else { value })
+        };
+
+        let len = match digits.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return None
+        };
 
         Some(Pixel {
-            r, g, b
+            r: channel(0, len)?,
+            g: channel(1, len)?,
+            b: channel(2, len)?
         })
     }
 
+    /// Parses a CSS color: hex, `rgb()`, `rgba()`, `hsl()`, `hsla()` or a named color, the alpha is ignored
+    /// # Example
+    /// ```
+    /// use rusty_imager::models::{Colors, Pixel};
+    /// 
+    /// assert_eq!(Pixel::from_css("rgb(255, 0, 0)"), Some(Colors::RED));
+    /// assert_eq!(Pixel::from_css("hsl(120 100% 25%)"), Some(Colors::GREEN));
+    /// assert_eq!(Pixel::from_css("#00f"), Some(Colors::BLUE));
+    /// assert_eq!(Pixel::from_css("white"), Some(Colors::WHITE));
+    /// ```
+    pub fn from_css(css: &str) -> Option<Self> {
+        let css = css.trim().to_ascii_lowercase();
+
+        if css.starts_with('#') {
+            return Pixel::from_hex(&css)
+        }
+
+        let Some((function, args)) = css.strip_suffix(')').and_then(|c| c.split_once('(')) else {
+            return Colors::by_name(&css)
+        };
+
+        let args: Vec<&str> = args
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|arg| !arg.is_empty())
+            .collect();
+
+        if args.len() != 3 && args.len() != 4 {
+            return None
+        }
+
+        match function.trim() {
+            "rgb" | "rgba" => {
+                let channel = |arg: &str| -> Option<u8> {
+                    let value = match arg.strip_suffix('%') {
+                        Some(percent) => percent.parse::<f32>().ok()? * 2.55,
+                        None => arg.parse::<f32>().ok()?
+                    };
+
+                    Some(value.round().clamp(0.0, 255.0) as u8)
+                };
+
+                Some(Pixel {
+                    r: channel(args[0])?,
+                    g: channel(args[1])?,
+                    b: channel(args[2])?
+                })
+            }
+
+            "hsl" | "hsla" => {
+                let hue: f32 = args[0].strip_suffix("deg").unwrap_or(args[0]).parse().ok()?;
+                let saturation: f32 = args[1].strip_suffix('%')?.parse().ok()?;
+                let lightness: f32 = args[2].strip_suffix('%')?.parse().ok()?;
+
+                Some(Pixel::from_hsl(hue, saturation / 100.0, lightness / 100.0))
+            }
+
+            _ => None
+        }
+    }
+
+    /// Creates a pixel from hue (degrees), saturation (0 - 1) and lightness (0 - 1)
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let m = lightness - chroma / 2.0;
+
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x)
+        };
+
+        Self {
+            r: ((r + m) * 255.0).round().clamp(0.0, 255.0) as u8,
+            g: ((g + m) * 255.0).round().clamp(0.0, 255.0) as u8,
+            b: ((b + m) * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    }
+
     pub fn to_hex(&self) -> String {
-        format!("#{:x}{:x}{:x}", self.r, self.g, self.b)
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
     }
 
     pub fn to_bytes(&self, reverse: bool) -> Vec<u8> {
@@ -40,6 +142,87 @@ impl Pixel {
         res
     }
 
+    /// Calculates the perceived brightness of the pixel (ITU-R BT.601)
+    pub fn luminance(&self) -> u8 {
+        ((self.r as u32 * 299 + self.g as u32 * 587 + self.b as u32 * 114 + 500) / 1000) as u8
+    }
+
+    /// Converts the pixel to YCbCr (ITU-R BT.601, full range)
+    pub fn to_ycbcr(&self) -> (f32, f32, f32) {
+        let r = self.r as f32;
+        let g = self.g as f32;
+        let b = self.b as f32;
+
+        let y = 0.299 * r + 0.587 * g + 0.114 * b;
+        let cb = 128.0 - 0.168736 * r - 0.331264 * g + 0.5 * b;
+        let cr = 128.0 + 0.5 * r - 0.418688 * g - 0.081312 * b;
+
+        (y, cb, cr)
+    }
+
+    /// Creates a pixel from YCbCr (ITU-R BT.601, full range)
+    pub fn from_ycbcr(y: f32, cb: f32, cr: f32) -> Self {
+        let r = y + 1.402 * (cr - 128.0);
+        let g = y - 0.344136 * (cb - 128.0) - 0.714136 * (cr - 128.0);
+        let b = y + 1.772 * (cb - 128.0);
+
+        Self {
+            r: r.round().clamp(0.0, 255.0) as u8,
+            g: g.round().clamp(0.0, 255.0) as u8,
+            b: b.round().clamp(0.0, 255.0) as u8
+        }
+    }
+
+    /// Converts the pixel from sRGB to CIE L*a*b* (D65 white point)
+    pub fn to_lab(&self) -> (f32, f32, f32) {
+        fn linear(value: u8) -> f32 {
+            let value = value as f32 / 255.0;
+
+            if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        fn f(t: f32) -> f32 {
+            if t > 0.008856 {
+                t.cbrt()
+            } else {
+                7.787 * t + 16.0 / 116.0
+            }
+        }
+
+        let r = linear(self.r);
+        let g = linear(self.g);
+        let b = linear(self.b);
+
+        let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+        let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+
+        (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+
+    /// Perceptual distance between two pixels (CIE76 delta E)
+    pub fn lab_distance(&self, other: &Pixel) -> f32 {
+        let (l1, a1, b1) = self.to_lab();
+        let (l2, a2, b2) = other.to_lab();
+
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// Squared euclidean distance between two pixels in RGB space
+    pub fn distance_squared(&self, other: &Pixel) -> u32 {
+        let r = self.r as i32 - other.r as i32;
+        let g = self.g as i32 - other.g as i32;
+        let b = self.b as i32 - other.b as i32;
+
+        (r * r + g * g + b * b) as u32
+    }
+
     pub fn invert(&self) -> Self {
         Self { 
             r: 255 - self.r,
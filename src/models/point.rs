@@ -0,0 +1,18 @@
+/// Position in an image, x is the column and y the row
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32
+}
+
+impl Point {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x, y
+        }
+    }
+
+    pub fn distance(&self, other: &Point) -> f32 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+/// Rectangular area of an image
+/// * `x` - left column
+/// * `y` - first row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize
+}
+
+impl Rect {
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x, y, width, height
+        }
+    }
+
+    /// Shrinks the rectangle so it fits into an image of the given size
+    pub fn clip(&self, width: usize, height: usize) -> Self {
+        let x = self.x.min(width);
+        let y = self.y.min(height);
+
+        Self {
+            x,
+            y,
+            width: self.width.min(width - x),
+            height: self.height.min(height - y)
+        }
+    }
+
+    pub fn area(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x && y >= self.y && x < self.x + self.width && y < self.y + self.height
+    }
+
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.x < other.x + other.width && other.x < self.x + self.width &&
+            self.y < other.y + other.height && other.y < self.y + self.height
+    }
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+use crate::Image;
+
+use super::{count_colors, Quantizer, Swatch};
+
+/// Image stored as indices into a palette of at most 256 colors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedImage {
+    width: usize,
+    height: usize,
+    palette: Vec<Pixel>,
+    indices: Vec<u8>
+}
+
+impl IndexedImage {
+    /// Reduces the image to the palette picked by the quantizer
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::quantization::{IndexedImage, MedianCut};
+    /// 
+    /// let img = Image::from_file("<path>").expect("File not found!");
+    /// let indexed = IndexedImage::from_image(&img, &MedianCut::new(16));
+    /// 
+    /// indexed.to_image().save("<name>.bmp").expect("Failed to save the image");
+    /// ```
+    pub fn from_image(img: &dyn ImageFormat, quantizer: &impl Quantizer) -> Self {
+        let palette = quantizer.palette(&count_colors(img));
+
+        Self::with_palette(img, palette)
+    }
+
+    /// Maps every pixel of the image to the nearest color of the palette
+    pub fn with_palette(img: &dyn ImageFormat, palette: Vec<Pixel>) -> Self {
+        assert!(!palette.is_empty() || img.get_width() * img.get_height() == 0, "Palette must not be empty");
+        assert!(palette.len() <= 256, "Palette can have at most 256 colors");
+
+        let width = img.get_width();
+        let height = img.get_height();
+        let mut cache: HashMap<Pixel, u8> = HashMap::new();
+        let mut indices: Vec<u8> = Vec::with_capacity(width * height);
+
+        for j in 0..height {
+            for i in 0..width {
+                let pixel = img.get_pixel(i, j).cloned().unwrap_or_default();
+                let idx = *cache.entry(pixel).or_insert_with_key(|pixel| nearest_index(&palette, pixel));
+
+                indices.push(idx);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            palette,
+            indices
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn palette(&self) -> &[Pixel] {
+        &self.palette
+    }
+
+    /// Palette indices stored row by row
+    pub fn indices(&self) -> &[u8] {
+        &self.indices
+    }
+
+    /// Gets the palette index of the pixel at x and y
+    pub fn get_index(&self, x: usize, y: usize) -> Option<u8> {
+        if x >= self.width || y >= self.height {
+            return None
+        }
+
+        self.indices.get(y * self.width + x).copied()
+    }
+
+    /// Gets the palette colors sorted by how much of the image they cover
+    pub fn swatches(&self) -> Vec<Swatch> {
+        let mut counts = vec![0usize; self.palette.len()];
+        for &idx in &self.indices {
+            counts[idx as usize] += 1;
+        }
+
+        let total = self.indices.len().max(1) as f32;
+        let mut res: Vec<Swatch> = self.palette.iter()
+            .zip(counts)
+            .filter(|(_, count)| *count > 0)
+            .map(|(color, count)| Swatch::new(color.clone(), count as f32 / total))
+            .collect();
+
+        res.sort_by(|a, b| b.proportion.total_cmp(&a.proportion));
+
+        res
+    }
+
+    /// Converts the indices back to colors
+    pub fn to_image(&self) -> Image {
+        let mut img = Image::new(self.width, self.height);
+
+        for (idx, &color) in self.indices.iter().enumerate() {
+            img.set_pixel(idx % self.width, idx / self.width, self.palette[color as usize].clone());
+        }
+
+        img
+    }
+}
+
+fn nearest_index(palette: &[Pixel], pixel: &Pixel) -> u8 {
+    palette.iter()
+        .enumerate()
+        .min_by_key(|(_, color)| color.distance_squared(pixel))
+        .map(|(idx, _)| idx as u8)
+        .unwrap_or(0)
+}
\ No newline at end of file
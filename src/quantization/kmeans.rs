@@ -0,0 +1,93 @@
+use crate::models::Pixel;
+
+use super::{MedianCut, Quantizer};
+
+/// Refines the median cut palette with Lloyd's k-means iterations
+pub struct KMeans {
+    colors: usize,
+    iterations: usize
+}
+
+impl KMeans {
+    /// * `colors` - maximum size of the palette (1 - 256)
+    /// * `iterations` - maximum number of refinement steps
+    pub fn new(colors: usize, iterations: usize) -> Self {
+        assert!((1..=256).contains(&colors), "Number of colors must be between 1 and 256");
+
+        Self {
+            colors,
+            iterations
+        }
+    }
+}
+
+impl Quantizer for KMeans {
+    fn palette(&self, colors: &[(Pixel, u32)]) -> Vec<Pixel> {
+        let mut centroids: Vec<(f32, f32, f32)> = MedianCut::new(self.colors)
+            .palette(colors)
+            .iter()
+            .map(|p| (p.r as f32, p.g as f32, p.b as f32))
+            .collect();
+
+        let mut assignment = vec![usize::MAX; colors.len()];
+
+        for _ in 0..self.iterations {
+            let mut changed = false;
+
+            for (idx, (pixel, _)) in colors.iter().enumerate() {
+                let nearest = nearest(&centroids, pixel);
+
+                if assignment[idx] != nearest {
+                    assignment[idx] = nearest;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+
+            let mut sums = vec![(0f64, 0f64, 0f64, 0f64); centroids.len()];
+            for (idx, (pixel, freq)) in colors.iter().enumerate() {
+                let sum = &mut sums[assignment[idx]];
+                let weight = *freq as f64;
+
+                sum.0 += pixel.r as f64 * weight;
+                sum.1 += pixel.g as f64 * weight;
+                sum.2 += pixel.b as f64 * weight;
+                sum.3 += weight;
+            }
+
+            // Empty clusters keep their previous center
+            for (centroid, sum) in centroids.iter_mut().zip(sums) {
+                if sum.3 > 0.0 {
+                    *centroid = ((sum.0 / sum.3) as f32, (sum.1 / sum.3) as f32, (sum.2 / sum.3) as f32);
+                }
+            }
+        }
+
+        centroids.iter()
+            .map(|c| Pixel {
+                r: c.0.round().clamp(0.0, 255.0) as u8,
+                g: c.1.round().clamp(0.0, 255.0) as u8,
+                b: c.2.round().clamp(0.0, 255.0) as u8
+            })
+            .collect()
+    }
+}
+
+fn nearest(centroids: &[(f32, f32, f32)], pixel: &Pixel) -> usize {
+    let mut best = 0;
+    let mut best_distance = f32::MAX;
+
+    for (idx, c) in centroids.iter().enumerate() {
+        let distance = (c.0 - pixel.r as f32).powi(2) + (c.1 - pixel.g as f32).powi(2) + (c.2 - pixel.b as f32).powi(2);
+
+        if distance < best_distance {
+            best_distance = distance;
+            best = idx;
+        }
+    }
+
+    best
+}
\ No newline at end of file
@@ -0,0 +1,145 @@
+use crate::models::Pixel;
+
+use super::quantizer::average;
+use super::Quantizer;
+
+/// Repeatedly cuts the color box with the largest error in two along its widest channel
+/// 
+/// The cut is placed where the two halves have the smallest total variance instead of the plain median
+pub struct MedianCut {
+    colors: usize
+}
+
+impl MedianCut {
+    /// * `colors` - maximum size of the palette (1 - 256)
+    pub fn new(colors: usize) -> Self {
+        assert!((1..=256).contains(&colors), "Number of colors must be between 1 and 256");
+
+        Self {
+            colors
+        }
+    }
+}
+
+impl Quantizer for MedianCut {
+    fn palette(&self, colors: &[(Pixel, u32)]) -> Vec<Pixel> {
+        if colors.is_empty() {
+            return vec![]
+        }
+
+        let mut boxes: Vec<Vec<(Pixel, u32)>> = vec![colors.to_vec()];
+
+        while boxes.len() < self.colors {
+            // Split the box with the largest error first
+            let worst = boxes.iter()
+                .enumerate()
+                .filter(|(_, b)| b.len() > 1)
+                .map(|(idx, b)| (idx, squared_error(b)))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+
+            let Some((idx, _)) = worst else {
+                break;
+            };
+
+            let mut colors = boxes.swap_remove(idx);
+            let channel = widest_channel(&colors);
+            colors.sort_by_key(|(pixel, _)| channel_value(pixel, channel));
+
+            let upper = colors.split_off(best_split(&colors));
+            boxes.push(colors);
+            boxes.push(upper);
+        }
+
+        boxes.iter().map(|b| average(b)).collect()
+    }
+}
+
+fn channel_value(pixel: &Pixel, channel: usize) -> u8 {
+    match channel {
+        0 => pixel.r,
+        1 => pixel.g,
+        _ => pixel.b
+    }
+}
+
+/// Weighted sums of the values and squared values of every channel
+#[derive(Default, Clone, Copy)]
+struct Moments {
+    weight: f64,
+    sum: [f64; 3],
+    sum_sq: [f64; 3]
+}
+
+impl Moments {
+    fn add(&mut self, pixel: &Pixel, freq: u32) {
+        let weight = freq as f64;
+        self.weight += weight;
+
+        for channel in 0..3 {
+            let value = channel_value(pixel, channel) as f64;
+            self.sum[channel] += value * weight;
+            self.sum_sq[channel] += value * value * weight;
+        }
+    }
+
+    fn channel_error(&self, channel: usize) -> f64 {
+        if self.weight == 0.0 {
+            return 0.0
+        }
+
+        self.sum_sq[channel] - self.sum[channel] * self.sum[channel] / self.weight
+    }
+
+    fn error(&self) -> f64 {
+        (0..3).map(|channel| self.channel_error(channel)).sum()
+    }
+}
+
+fn moments(colors: &[(Pixel, u32)]) -> Moments {
+    let mut res = Moments::default();
+    for (pixel, freq) in colors {
+        res.add(pixel, *freq);
+    }
+
+    res
+}
+
+fn squared_error(colors: &[(Pixel, u32)]) -> f64 {
+    moments(colors).error()
+}
+
+/// Finds the channel with the largest variance
+fn widest_channel(colors: &[(Pixel, u32)]) -> usize {
+    let moments = moments(colors);
+
+    (0..3)
+        .max_by(|&a, &b| moments.channel_error(a).total_cmp(&moments.channel_error(b)).then(b.cmp(&a)))
+        .unwrap()
+}
+
+/// Finds the cut of the sorted colors that leaves the two halves with the smallest total error
+fn best_split(colors: &[(Pixel, u32)]) -> usize {
+    let total = moments(colors);
+    let mut lower = Moments::default();
+    let mut best = colors.len() / 2;
+    let mut best_error = f64::MAX;
+
+    for (i, (pixel, freq)) in colors.iter().enumerate().take(colors.len() - 1) {
+        lower.add(pixel, *freq);
+
+        let mut upper = total;
+        upper.weight -= lower.weight;
+        for channel in 0..3 {
+            upper.sum[channel] -= lower.sum[channel];
+            upper.sum_sq[channel] -= lower.sum_sq[channel];
+        }
+
+        let error = lower.error() + upper.error();
+        if error < best_error {
+            best_error = error;
+            best = i + 1;
+        }
+    }
+
+    best
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+mod quantizer;
+mod median_cut;
+mod octree;
+mod kmeans;
+mod indexed_image;
+mod swatch;
+
+pub use quantizer::{count_colors, Quantizer};
+pub use median_cut::MedianCut;
+pub use octree::Octree;
+pub use kmeans::KMeans;
+pub use indexed_image::IndexedImage;
+pub use swatch::Swatch;
\ No newline at end of file
@@ -0,0 +1,124 @@
+use crate::models::Pixel;
+
+use super::Quantizer;
+
+const DEPTH: usize = 8;
+
+#[derive(Default)]
+struct Node {
+    children: [Option<usize>; 8],
+    red: u64,
+    green: u64,
+    blue: u64,
+    count: u64,
+    leaf: bool
+}
+
+/// Builds an octree of the colors and merges the least used branches
+pub struct Octree {
+    colors: usize
+}
+
+impl Octree {
+    /// * `colors` - maximum size of the palette (1 - 256)
+    pub fn new(colors: usize) -> Self {
+        assert!((1..=256).contains(&colors), "Number of colors must be between 1 and 256");
+
+        Self {
+            colors
+        }
+    }
+}
+
+impl Quantizer for Octree {
+    fn palette(&self, colors: &[(Pixel, u32)]) -> Vec<Pixel> {
+        let mut nodes: Vec<Node> = vec![Node::default()];
+        let mut levels: Vec<Vec<usize>> = vec![vec![]; DEPTH];
+        let mut leaves = 0;
+
+        for (pixel, freq) in colors {
+            let mut node = 0;
+
+            for (level, reducible) in levels.iter_mut().enumerate() {
+                nodes[node].count += *freq as u64;
+
+                let shift = 7 - level;
+                let child = (((pixel.r >> shift) & 1) << 2 | ((pixel.g >> shift) & 1) << 1 | ((pixel.b >> shift) & 1)) as usize;
+
+                node = match nodes[node].children[child] {
+                    Some(next) => next,
+                    None => {
+                        if nodes[node].children.iter().all(Option::is_none) {
+                            reducible.push(node);
+                        }
+
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].children[child] = Some(next);
+
+                        if level == DEPTH - 1 {
+                            nodes[next].leaf = true;
+                            leaves += 1;
+                        }
+
+                        next
+                    }
+                };
+            }
+
+            let leaf = &mut nodes[node];
+            leaf.red += pixel.r as u64 * *freq as u64;
+            leaf.green += pixel.g as u64 * *freq as u64;
+            leaf.blue += pixel.b as u64 * *freq as u64;
+            leaf.count += *freq as u64;
+        }
+
+        // Merge the children of the least used node on the deepest level until the palette fits
+        while leaves > self.colors {
+            let Some(level) = levels.iter().rposition(|l| !l.is_empty()) else {
+                break;
+            };
+
+            let (pos, _) = levels[level].iter()
+                .enumerate()
+                .min_by_key(|(_, &node)| nodes[node].count)
+                .unwrap();
+            let node = levels[level].swap_remove(pos);
+
+            let children = std::mem::take(&mut nodes[node].children);
+            let mut merged = 0;
+
+            for child in children.into_iter().flatten() {
+                let (r, g, b) = (nodes[child].red, nodes[child].green, nodes[child].blue);
+                nodes[node].red += r;
+                nodes[node].green += g;
+                nodes[node].blue += b;
+                merged += 1;
+            }
+
+            nodes[node].leaf = true;
+            leaves = leaves + 1 - merged;
+        }
+
+        let mut res: Vec<Pixel> = vec![];
+        let mut stack = vec![0];
+
+        while let Some(node) = stack.pop() {
+            let current = &nodes[node];
+
+            if current.leaf {
+                let count = current.count.max(1);
+
+                res.push(Pixel {
+                    r: ((current.red + count / 2) / count) as u8,
+                    g: ((current.green + count / 2) / count) as u8,
+                    b: ((current.blue + count / 2) / count) as u8
+                });
+            } else {
+                stack.extend(current.children.iter().rev().flatten());
+            }
+        }
+
+        res
+    }
+}
\ No newline at end of file
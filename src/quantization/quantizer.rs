@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use crate::formats::ImageFormat;
+use crate::models::Pixel;
+
+pub trait Quantizer {
+    /// Picks the palette from the unique colors of an image and the number of pixels of each color
+    fn palette(&self, colors: &[(Pixel, u32)]) -> Vec<Pixel>;
+}
+
+/// Counts how many pixels of each color the image contains
+pub fn count_colors(img: &dyn ImageFormat) -> Vec<(Pixel, u32)> {
+    let mut freq: HashMap<Pixel, u32> = HashMap::new();
+
+    for i in 0..img.get_width() {
+        for j in 0..img.get_height() {
+            if let Some(pixel) = img.get_pixel(i, j) {
+                *freq.entry(pixel.clone()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut res: Vec<(Pixel, u32)> = freq.into_iter().collect();
+    res.sort_by_key(|(pixel, _)| (pixel.r, pixel.g, pixel.b));
+
+    res
+}
+
+/// Calculates the weighted average color
+pub(super) fn average(colors: &[(Pixel, u32)]) -> Pixel {
+    let mut r = 0u64;
+    let mut g = 0u64;
+    let mut b = 0u64;
+    let mut count = 0u64;
+
+    for (pixel, freq) in colors {
+        r += pixel.r as u64 * *freq as u64;
+        g += pixel.g as u64 * *freq as u64;
+        b += pixel.b as u64 * *freq as u64;
+        count += *freq as u64;
+    }
+
+    if count == 0 {
+        return Pixel::default()
+    }
+
+    Pixel {
+        r: ((r + count / 2) / count) as u8,
+        g: ((g + count / 2) / count) as u8,
+        b: ((b + count / 2) / count) as u8
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use crate::formats::ImageFormat;
+use crate::models::{Colors, Pixel};
+
+use super::{IndexedImage, KMeans};
+
+/// Color of a palette with the portion of the image it covers
+#[derive(Debug, Clone, PartialEq)]
+pub struct Swatch {
+    pub color: Pixel,
+    /// Portion of the pixels (0 - 1)
+    pub proportion: f32,
+    /// Name of the nearest named color
+    pub name: &'static str
+}
+
+impl Swatch {
+    pub fn new(color: Pixel, proportion: f32) -> Self {
+        let (name, _) = Colors::nearest(&color);
+
+        Self {
+            color,
+            proportion,
+            name
+        }
+    }
+
+    /// Extracts the dominant colors of the image, the most common first
+    /// * `count` - maximal number of colors, at most 256 are extracted and 0 gives no swatches
+    /// # Example
+    /// ```no_run
+    /// use rusty_imager::Image;
+    /// use rusty_imager::quantization::Swatch;
+    /// 
+    /// let img = Image::from_file("<path>").expect("File not found!");
+    /// for swatch in Swatch::dominant(&img, 5) {
+    ///     println!("{} ({}) {:.1}%", swatch.color.to_hex(), swatch.name, swatch.proportion * 100.0);
+    /// }
+    /// ```
+    pub fn dominant(img: &dyn ImageFormat, count: usize) -> Vec<Swatch> {
+        if count == 0 {
+            return vec![];
+        }
+
+        IndexedImage::from_image(img, &KMeans::new(count.min(256), 10)).swatches()
+    }
+}
\ No newline at end of file
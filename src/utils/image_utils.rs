@@ -0,0 +1,90 @@
+use crate::formats::ImageFormat;
+
+/// Collects the luminance of every pixel row by row
+pub fn luminance_plane(img: &dyn ImageFormat) -> Vec<u8> {
+    let width = img.get_width();
+    let height = img.get_height();
+    let mut res: Vec<u8> = Vec::with_capacity(width * height);
+
+    for j in 0..height {
+        for i in 0..width {
+            res.push(img.get_pixel(i, j).map(|p| p.luminance()).unwrap_or_default());
+        }
+    }
+
+    res
+}
+
+/// Convolves a row by row stored plane with a 1D kernel horizontally and then vertically
+/// 
+/// Edge values are repeated outside of the plane
+pub fn separable_convolution(plane: &[f32], width: usize, height: usize, kernel: &[f32]) -> Vec<f32> {
+    let half = kernel.len() as isize / 2;
+    let mut tmp = vec![0f32; plane.len()];
+    let mut res = vec![0f32; plane.len()];
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = 0f32;
+            for (k, weight) in kernel.iter().enumerate() {
+                let sx = (x as isize + k as isize - half).clamp(0, width as isize - 1) as usize;
+                sum += plane[y * width + sx] * weight;
+            }
+            tmp[y * width + x] = sum;
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = 0f32;
+            for (k, weight) in kernel.iter().enumerate() {
+                let sy = (y as isize + k as isize - half).clamp(0, height as isize - 1) as usize;
+                sum += tmp[sy * width + x] * weight;
+            }
+            res[y * width + x] = sum;
+        }
+    }
+
+    res
+}
+
+/// Resamples a row by row stored plane to a new size, every new value is the area weighted average of the values it covers
+pub fn resample_plane(plane: &[f32], width: usize, height: usize, new_width: usize, new_height: usize) -> Vec<f32> {
+    let columns = area_weights(width, new_width);
+    let rows = area_weights(height, new_height);
+    let mut tmp = vec![0f32; new_width * height];
+    let mut res = vec![0f32; new_width * new_height];
+
+    for y in 0..height {
+        for (x, weights) in columns.iter().enumerate() {
+            tmp[y * new_width + x] = weights.iter().map(|&(sx, w)| plane[y * width + sx] * w).sum();
+        }
+    }
+
+    for (y, weights) in rows.iter().enumerate() {
+        for x in 0..new_width {
+            res[y * new_width + x] = weights.iter().map(|&(sy, w)| tmp[sy * new_width + x] * w).sum();
+        }
+    }
+
+    res
+}
+
+/// Source indices and their normalized coverage for every destination index
+fn area_weights(size: usize, new_size: usize) -> Vec<Vec<(usize, f32)>> {
+    let scale = size as f32 / new_size as f32;
+
+    (0..new_size).map(|i| {
+        let start = i as f32 * scale;
+        let end = (start + scale).min(size as f32);
+        let first = start.floor() as usize;
+        let last = (end.ceil() as usize).clamp(first + 1, size);
+
+        let weights: Vec<(usize, f32)> = (first..last)
+            .map(|s| (s, (end.min(s as f32 + 1.0) - start.max(s as f32)).max(0.0)))
+            .collect();
+
+        let total: f32 = weights.iter().map(|w| w.1).sum();
+        weights.into_iter().map(|(s, w)| (s, w / total)).collect()
+    }).collect()
+}
\ No newline at end of file
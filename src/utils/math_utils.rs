@@ -1,7 +1,7 @@
 use std::f32::consts::{E, PI};
 
 pub fn calculate_gaussian_kernel(sigma: f32, size: u32) -> Vec<Vec<f32>> {
-    assert!(size % 2 != 0);
+    assert!(!size.is_multiple_of(2));
 
     let mut res: Vec<Vec<f32>> = vec![vec![0f32; size as usize]; size as usize];
     let half: i32 = size as i32 / 2;
@@ -19,11 +19,28 @@ pub fn calculate_gaussian_kernel(sigma: f32, size: u32) -> Vec<Vec<f32>> {
     }
 
     // Normalization
-    for i in 0..size as usize {
-        for j in 0..size as usize {
-            res[i][j] /= sum;
+    for row in res.iter_mut() {
+        for value in row.iter_mut() {
+            *value /= sum;
         }
     }
 
+    res
+}
+
+pub fn calculate_gaussian_kernel_1d(sigma: f32, size: u32) -> Vec<f32> {
+    assert!(!size.is_multiple_of(2));
+
+    let half: i32 = size as i32 / 2;
+    let mut res: Vec<f32> = (-half..=half)
+        .map(|i| E.powf(-((i * i) as f32) / (2.0 * sigma.powi(2))))
+        .collect();
+
+    // Normalization
+    let sum: f32 = res.iter().sum();
+    for value in res.iter_mut() {
+        *value /= sum;
+    }
+
     res
 }
\ No newline at end of file
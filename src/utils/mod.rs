@@ -1,3 +1,7 @@
 mod math_utils;
+mod image_utils;
+mod random;
 
-pub use math_utils::*;
\ No newline at end of file
+pub use math_utils::*;
+pub use image_utils::*;
+pub use random::Random;
\ No newline at end of file
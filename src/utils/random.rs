@@ -0,0 +1,34 @@
+/// Small deterministic pseudo random generator (xorshift64*)
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        let state = seed ^ 0x9E37_79B9_7F4A_7C15;
+
+        Self {
+            // Zero state would only produce zeros
+            state: if state == 0 { 0x9E37_79B9_7F4A_7C15 } else { state }
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Uniform number in range 0..1
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform number in range 0..max
+    pub fn below(&mut self, max: usize) -> usize {
+        (self.next_u64() % max as u64) as usize
+    }
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::{difference, ms_ssim, mse, psnr, ssim};
+use rusty_imager::filters::BoxBlur;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::Pixel;
+
+use crate::common::noise_image;
+
+#[test]
+fn test_mse_and_psnr() {
+    let a = Image::new(10, 10);
+    let mut b = Image::new(10, 10);
+
+    assert_eq!(mse(&a, &b), 0.0);
+    assert_eq!(psnr(&a, &b), f64::INFINITY);
+
+    // 300 samples, three of them differ by 10
+    b.set_pixel(3, 4, Pixel { r: 10, g: 10, b: 10 });
+    assert!((mse(&a, &b) - 1.0).abs() < 1e-9);
+    assert!((psnr(&a, &b) - 48.1308).abs() < 1e-3);
+}
+
+#[test]
+fn test_ssim() {
+    let original = noise_image(64, 64, 1);
+    let mut blurred = noise_image(64, 64, 1);
+    blurred.apply_filter(BoxBlur::new(3));
+
+    assert!((ssim(&original, &original) - 1.0).abs() < 1e-6);
+    assert!((ms_ssim(&original, &original) - 1.0).abs() < 1e-6);
+
+    let single = ssim(&original, &blurred);
+    let multi = ms_ssim(&original, &blurred);
+    assert!(single > 0.0 && single < 0.9);
+    assert!(multi > single && multi < 1.0);
+}
+
+#[test]
+fn test_difference() {
+    let a = Image::new(4, 3);
+    let mut b = Image::new(4, 3);
+    b.set_pixel(0, 0, Pixel { r: 2, g: 0, b: 0 });
+    b.set_pixel(1, 2, Pixel { r: 0, g: 40, b: 5 });
+
+    let diff = difference(&a, &b, 2);
+
+    assert_eq!(diff.count, 1);
+    assert_eq!(diff.max, 40);
+    assert_eq!(diff.image.get_pixel(0, 0), Some(&Pixel { r: 0, g: 0, b: 0 }));
+    assert_eq!(diff.image.get_pixel(1, 2), Some(&Pixel { r: 0, g: 40, b: 5 }));
+    assert_eq!(difference(&a, &b, 0).count, 2);
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::Components;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Connectivity, Rect};
+
+/// 3x2 block, two diagonally touching pixels and a single speck
+fn blobs_image() -> Image {
+    let mut img = Image::new(12, 8);
+    for x in 1..4 {
+        for y in 1..3 {
+            img.set_pixel(x, y, Colors::WHITE);
+        }
+    }
+
+    img.set_pixel(7, 5, Colors::WHITE);
+    img.set_pixel(8, 6, Colors::WHITE);
+    img.set_pixel(11, 0, Colors::WHITE);
+
+    img
+}
+
+#[test]
+fn test_connectivity() {
+    let img = blobs_image();
+
+    assert_eq!(Components::from_image(&img, Connectivity::Four).components().len(), 4);
+    assert_eq!(Components::from_image(&img, Connectivity::Eight).components().len(), 3);
+}
+
+#[test]
+fn test_component_stats() {
+    let components = Components::from_image(&blobs_image(), Connectivity::Eight);
+    let label = components.get_label(2, 2).unwrap();
+    let block = &components.components()[label as usize - 1];
+
+    assert_eq!(block.area, 6);
+    assert_eq!(block.bounds, Rect::new(1, 1, 3, 2));
+    assert_eq!(block.centroid, (2.0, 1.5));
+    assert_eq!(block.perimeter, 10);
+    assert_eq!(block.label, label);
+    assert_eq!(components.get_label(0, 0), Some(0));
+}
+
+#[test]
+fn test_filter_by_area() {
+    let components = Components::from_image(&blobs_image(), Connectivity::Eight).filter_by_area(2, 100);
+
+    assert_eq!(components.components().len(), 2);
+    assert_eq!(components.get_label(11, 0), Some(0));
+    assert_eq!(components.get_label(8, 6), components.get_label(7, 5));
+    assert_ne!(components.get_label(8, 6), Some(0));
+
+    let img = components.to_image();
+    assert_eq!(img.get_pixel(11, 0), Some(&Colors::BLACK));
+    assert_eq!(img.get_pixel(7, 5), Some(&Colors::WHITE));
+}
+
+#[test]
+#[should_panic(expected = "Mask must match the dimensions")]
+fn test_mask_size_mismatch() {
+    Components::from_mask(&[true; 5], 3, 2, Connectivity::Four);
+}
\ No newline at end of file
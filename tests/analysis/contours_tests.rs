@@ -0,0 +1,61 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::{convex_hull, min_area_rect, Contour};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Point};
+
+/// Square ring (outer 2..14, hole 5..11) with a dot inside the hole
+fn ring_image() -> Image {
+    let mut img = Image::new(16, 16);
+    for x in 2..14 {
+        for y in 2..14 {
+            if !((5..11).contains(&x) && (5..11).contains(&y)) {
+                img.set_pixel(x, y, Colors::WHITE);
+            }
+        }
+    }
+
+    img.set_pixel(8, 8, Colors::WHITE);
+    img
+}
+
+#[test]
+fn test_hierarchy() {
+    let contours = Contour::find_all(&ring_image());
+
+    assert_eq!(contours.len(), 3);
+
+    let outer = contours.iter().position(|c| !c.hole && c.parent.is_none()).unwrap();
+    let hole = contours.iter().position(|c| c.hole).unwrap();
+    let dot = contours.iter().position(|c| !c.hole && c.parent.is_some()).unwrap();
+
+    assert_eq!(contours[hole].parent, Some(outer));
+    assert_eq!(contours[dot].parent, Some(hole));
+    assert_eq!(contours[dot].points, vec![Point::new(8.0, 8.0)]);
+}
+
+#[test]
+fn test_contour_measurements() {
+    let contours = Contour::find_all(&ring_image());
+    let outer = contours.iter().find(|c| !c.hole && c.parent.is_none()).unwrap();
+
+    assert_eq!(outer.points.len(), 44);
+    assert_eq!(outer.area(), 121.0);
+    assert_eq!(outer.perimeter(), 44.0);
+
+    let polygon = outer.approximate(1.0);
+    assert_eq!(polygon.len(), 4);
+    assert_eq!(outer.convex_hull().len(), 4);
+}
+
+#[test]
+fn test_min_area_rect() {
+    // Diamond, the smallest rectangle is rotated by 45 degrees
+    let points = [Point::new(0.0, 5.0), Point::new(5.0, 0.0), Point::new(10.0, 5.0), Point::new(5.0, 10.0), Point::new(5.0, 5.0)];
+
+    assert_eq!(convex_hull(&points).len(), 4);
+
+    let rect = min_area_rect(&points).unwrap();
+    assert!((rect.area() - 50.0).abs() < 1e-3);
+    assert!((rect.center.x - 5.0).abs() < 1e-3 && (rect.center.y - 5.0).abs() < 1e-3);
+    assert!((rect.angle.abs() % 90.0 - 45.0).abs() < 1e-3);
+}
\ No newline at end of file
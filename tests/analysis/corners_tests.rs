@@ -0,0 +1,53 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::{CornerResponse, Fast, Keypoint};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::Colors;
+
+/// White square covering 10..20 on a black background
+fn square_image() -> Image {
+    let mut img = Image::new(30, 30);
+    for x in 10..20 {
+        for y in 10..20 {
+            img.set_pixel(x, y, Colors::WHITE);
+        }
+    }
+
+    img
+}
+
+fn near_corner(keypoint: &Keypoint, tolerance: f32) -> bool {
+    [(10.0, 10.0), (19.0, 10.0), (10.0, 19.0), (19.0, 19.0)].iter().any(|(x, y)| {
+        (keypoint.position.x - x).abs() <= tolerance && (keypoint.position.y - y).abs() <= tolerance
+    })
+}
+
+#[test]
+fn test_harris() {
+    let keypoints = CornerResponse::harris(&square_image(), 0.04, 1.0).keypoints(0.1, 3);
+
+    assert_eq!(keypoints.len(), 4);
+    assert!(keypoints.iter().all(|k| near_corner(k, 1.0)));
+}
+
+#[test]
+fn test_shi_tomasi() {
+    let response = CornerResponse::shi_tomasi(&square_image(), 1.0);
+    let keypoints = response.keypoints(0.1, 3);
+
+    assert_eq!(keypoints.len(), 4);
+    assert!(keypoints.iter().all(|k| near_corner(k, 1.0)));
+
+    // Edges and flat areas have no response
+    assert!(response.values()[15 * 30 + 15].abs() < 1e-6);
+    assert!(response.values()[10 * 30 + 15] < keypoints[3].score * 0.1);
+}
+
+#[test]
+fn test_fast() {
+    let keypoints = Fast::new(50).detect(&square_image());
+
+    assert_eq!(keypoints.len(), 4);
+    assert!(keypoints.iter().all(|k| near_corner(k, 1.0)));
+
+    assert!(Fast::new(50).detect(&Image::new(30, 30)).is_empty());
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::{match_descriptors, Homography, Orb};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Pixel, Point};
+use rusty_imager::utils::Random;
+
+/// Random gray blocks, `shift` moves the whole pattern right and up
+fn blocks_image(shift: (usize, usize)) -> Image {
+    let mut random = Random::new(7);
+    let values: Vec<u8> = (0..20 * 20).map(|_| random.below(256) as u8).collect();
+
+    let mut img = Image::new(120, 120);
+    for y in shift.1..120 {
+        for x in shift.0..120 {
+            let v = values[((y - shift.1) / 6) * 20 + (x - shift.0) / 6];
+            img.set_pixel(x, y, Pixel { r: v, g: v, b: v });
+        }
+    }
+
+    img
+}
+
+#[test]
+fn test_descriptors_of_same_image_match() {
+    let orb = Orb::new(100, 20);
+    let features = orb.detect(&blocks_image((0, 0)));
+
+    assert!(!features.is_empty());
+    assert!(features.len() <= 100);
+
+    let matches = match_descriptors(&features, &features, 1.0);
+    assert_eq!(matches.len(), features.len());
+    assert!(matches.iter().all(|m| m.distance == 0));
+}
+
+#[test]
+fn test_alignment_of_shifted_images() {
+    let orb = Orb::new(200, 20);
+    let a = orb.detect(&blocks_image((0, 0)));
+    let b = orb.detect(&blocks_image((7, 4)));
+
+    let pairs: Vec<(Point, Point)> = match_descriptors(&a, &b, 0.8).iter()
+        .map(|m| (a[m.query].keypoint.position, b[m.train].keypoint.position))
+        .collect();
+
+    let (homography, inliers) = Homography::ransac(&pairs, 1.5, 500).unwrap();
+    let mapped = homography.apply(&Point::new(50.0, 50.0));
+
+    assert!(inliers.iter().filter(|&&i| i).count() >= 8);
+    assert!(mapped.distance(&Point::new(57.0, 54.0)) < 0.5);
+}
+
+#[test]
+fn test_homography_fit() {
+    let truth = Homography { matrix: [1.2, 0.1, 5.0, -0.05, 0.9, 12.0, 0.001, 0.0005, 1.0] };
+    let mut pairs: Vec<(Point, Point)> = [(0.0, 0.0), (100.0, 0.0), (0.0, 80.0), (100.0, 80.0), (40.0, 30.0), (70.0, 60.0)]
+        .iter()
+        .map(|&(x, y)| (Point::new(x, y), truth.apply(&Point::new(x, y))))
+        .collect();
+
+    let exact = Homography::from_points(&pairs).unwrap();
+    for (a, b) in exact.matrix.iter().zip(truth.matrix) {
+        assert!((a - b).abs() < 1e-3);
+    }
+
+    // Mismatched pairs are rejected
+    pairs.push((Point::new(10.0, 10.0), Point::new(90.0, 3.0)));
+    pairs.push((Point::new(60.0, 20.0), Point::new(0.0, 70.0)));
+
+    let (robust, inliers) = Homography::ransac(&pairs, 1.0, 200).unwrap();
+    assert_eq!(inliers, vec![true, true, true, true, true, true, false, false]);
+    assert!(robust.apply(&Point::new(50.0, 50.0)).distance(&truth.apply(&Point::new(50.0, 50.0))) < 0.01);
+
+    let inverse = truth.inverse().unwrap();
+    assert!(inverse.apply(&truth.apply(&Point::new(3.0, 4.0))).distance(&Point::new(3.0, 4.0)) < 1e-3);
+}
+
+#[test]
+fn test_warp_translation() {
+    let img = blocks_image((0, 0));
+    let shift = Homography { matrix: [1.0, 0.0, 7.0, 0.0, 1.0, 4.0, 0.0, 0.0, 1.0] };
+    let warped = shift.warp(&img, 120, 120);
+
+    assert_eq!(warped.get_pixel(30, 40), img.get_pixel(23, 36));
+    assert_eq!(warped.get_pixel(2, 2), Some(&Pixel { r: 0, g: 0, b: 0 }));
+}
+
+#[test]
+fn test_rotation_invariance() {
+    let img = blocks_image((0, 0));
+    let mut rotated = Image::new(120, 120);
+    for y in 0..120 {
+        for x in 0..120 {
+            rotated.set_pixel(119 - y, x, img.get_pixel(x, y).unwrap().clone());
+        }
+    }
+
+    let orb = Orb::new(200, 20);
+    let a = orb.detect(&img);
+    let b = orb.detect(&rotated);
+
+    let pairs: Vec<(Point, Point)> = match_descriptors(&a, &b, 0.8).iter()
+        .map(|m| (a[m.query].keypoint.position, b[m.train].keypoint.position))
+        .collect();
+
+    let (homography, _) = Homography::ransac(&pairs, 1.5, 500).unwrap();
+    assert!(homography.apply(&Point::new(50.0, 30.0)).distance(&Point::new(89.0, 50.0)) < 0.5);
+}
\ No newline at end of file
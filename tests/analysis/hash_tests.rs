@@ -0,0 +1,72 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::ImageHash;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::Pixel;
+use rusty_imager::utils::Random;
+
+type Hasher = fn(&dyn ImageFormat) -> ImageHash;
+
+const HASHERS: [Hasher; 4] = [ImageHash::average, ImageHash::difference, ImageHash::perceptual, ImageHash::wavelet];
+
+/// Smooth waves with a bright rectangle
+fn scene() -> Image {
+    let mut img = Image::new(160, 120);
+    for y in 0..120 {
+        for x in 0..160 {
+            let mut v = 128.0 + 60.0 * (x as f32 / 17.0).sin() + 50.0 * (y as f32 / 11.0).cos();
+            if (40..90).contains(&x) && (30..60).contains(&y) {
+                v = 250.0;
+            }
+
+            let v = v.clamp(0.0, 255.0) as u8;
+            img.set_pixel(x, y, Pixel { r: v, g: v / 2, b: 255 - v });
+        }
+    }
+
+    img
+}
+
+fn random_blocks() -> Image {
+    let mut random = Random::new(99);
+    let values: Vec<u8> = (0..16 * 12).map(|_| random.below(256) as u8).collect();
+
+    let mut img = Image::new(160, 120);
+    for y in 0..120 {
+        for x in 0..160 {
+            let v = values[(y / 10) * 16 + x / 10];
+            img.set_pixel(x, y, Pixel { r: v, g: v, b: v });
+        }
+    }
+
+    img
+}
+
+#[test]
+fn test_hashes_survive_resizing() {
+    let original = scene();
+    let smaller = original.resize(80, 60);
+    let stretched = original.resize(200, 100);
+
+    for hasher in HASHERS {
+        assert_eq!(hasher(&original), hasher(&original));
+        assert!(hasher(&original).distance(&hasher(&smaller)) <= 4);
+        assert!(hasher(&original).distance(&hasher(&stretched)) <= 6);
+    }
+}
+
+#[test]
+fn test_hashes_tell_images_apart() {
+    for hasher in HASHERS {
+        assert!(hasher(&scene()).distance(&hasher(&random_blocks())) >= 16);
+    }
+}
+
+#[test]
+fn test_hex_roundtrip() {
+    let hash = ImageHash::perceptual(&scene());
+
+    assert_eq!(hash.to_hex().len(), 16);
+    assert_eq!(ImageHash::from_hex(&hash.to_hex()), Some(hash));
+    assert_eq!(ImageHash::new(0xff).distance(&ImageHash::new(0x0f)), 4);
+    assert_eq!(ImageHash::from_hex("xyz"), None);
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+use std::f32::consts::PI;
+
+use rusty_imager::Image;
+use rusty_imager::analysis::{HoughCircle, HoughLine, LineSegment};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Point};
+
+#[test]
+fn test_standard_lines() {
+    let mut img = Image::new(50, 30);
+    for x in 0..50 {
+        img.set_pixel(x, 10, Colors::WHITE);
+    }
+
+    let lines = HoughLine::detect(&img, 40);
+
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].votes, 50);
+    assert!((lines[0].theta - PI / 2.0).abs() < 1e-4);
+    assert!((lines[0].rho - 10.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_probabilistic_segments() {
+    let mut img = Image::new(60, 60);
+    LineSegment { start: Point::new(5.0, 10.0), end: Point::new(45.0, 10.0), votes: 0 }.draw(&mut img, Colors::WHITE);
+    LineSegment { start: Point::new(50.0, 15.0), end: Point::new(50.0, 55.0), votes: 0 }.draw(&mut img, Colors::WHITE);
+
+    let mut segments = LineSegment::detect(&img, 15, 20.0, 2);
+    segments.sort_by(|a, b| a.start.x.total_cmp(&b.start.x));
+
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].votes, 41);
+    assert!((segments[0].length() - 40.0).abs() <= 1.0);
+    assert_eq!(segments[0].start.y, 10.0);
+    assert_eq!(segments[1].start.x, 50.0);
+    assert!((segments[1].length() - 40.0).abs() <= 1.0);
+}
+
+#[test]
+fn test_circles() {
+    let mut img = Image::new(64, 64);
+    HoughCircle { center: Point::new(20.0, 20.0), radius: 10.0, votes: 0 }.draw(&mut img, Colors::WHITE);
+    HoughCircle { center: Point::new(44.0, 40.0), radius: 14.0, votes: 0 }.draw(&mut img, Colors::WHITE);
+
+    let mut circles = HoughCircle::detect(&img, 6, 18, 0.8);
+    circles.sort_by(|a, b| a.radius.total_cmp(&b.radius));
+
+    assert_eq!(circles.len(), 2);
+    assert_eq!(circles[0].center, Point::new(20.0, 20.0));
+    assert_eq!(circles[0].radius, 10.0);
+    assert_eq!(circles[1].center, Point::new(44.0, 40.0));
+    assert_eq!(circles[1].radius, 14.0);
+}
+
+#[test]
+fn test_draw_line_across_image() {
+    let mut img = Image::new(20, 20);
+    HoughLine { rho: 5.0, theta: 0.0, votes: 0 }.draw(&mut img, Colors::RED);
+
+    for y in 0..20 {
+        assert_eq!(img.get_pixel(5, y), Some(&Colors::RED));
+    }
+    assert_eq!(img.get_pixel(6, 10), Some(&Colors::BLACK));
+}
+
+#[test]
+fn test_segment_near_border() {
+    // Shallow diagonal ending on the bottom row, walking along the rounded direction used to leave the mask
+    let (width, height) = (7, 5);
+    let mut mask = vec![false; width * height];
+    for (x, y) in [(0, 4), (1, 4), (2, 3), (3, 3), (4, 3), (5, 2), (6, 2)] {
+        mask[y * width + x] = true;
+    }
+
+    let segments = LineSegment::detect_in_mask(&mask, width, height, 2, 1.0, 2);
+
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].votes, 7);
+    assert!((segments[0].length() - 40f32.sqrt()).abs() < 1e-4);
+}
\ No newline at end of file
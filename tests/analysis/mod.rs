@@ -0,0 +1,9 @@
+mod statistics_tests;
+mod components_tests;
+mod contours_tests;
+mod hough_tests;
+mod corners_tests;
+mod features_tests;
+mod template_tests;
+mod comparison_tests;
+mod hash_tests;
\ No newline at end of file
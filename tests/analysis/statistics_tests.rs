@@ -0,0 +1,51 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::{Histogram, Statistics};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Channel, Pixel, Rect};
+
+fn gradient_image() -> Image {
+    let mut img = Image::new(4, 4);
+
+    for x in 0..4 {
+        for y in 0..4 {
+            let value = (x * 4 + y) as u8 * 10;
+            img.set_pixel(x, y, Pixel { r: value, g: value, b: 255 - value });
+        }
+    }
+
+    img
+}
+
+#[test]
+fn test_histogram_counts() {
+    let hist = Histogram::from_image(&gradient_image());
+
+    assert_eq!(hist.count(), 16);
+    assert_eq!(hist.get(Channel::Red)[0], 1);
+    assert_eq!(hist.get(Channel::Blue)[255], 1);
+    assert_eq!(hist.cumulative(Channel::Green)[255], 16);
+}
+
+#[test]
+fn test_statistics() {
+    let stats = Statistics::from_image(&gradient_image(), Channel::Red).unwrap();
+
+    assert_eq!(stats.min, 0);
+    assert_eq!(stats.max, 150);
+    assert_eq!(stats.median, 70);
+    assert!((stats.mean - 75.0).abs() < 1e-3);
+    assert!((stats.std_dev - 46.1).abs() < 0.1);
+}
+
+#[test]
+fn test_region_and_percentile() {
+    let img = gradient_image();
+    let hist = Histogram::from_region(&img, Rect::new(2, 2, 10, 10));
+
+    assert_eq!(hist.count(), 4);
+    assert_eq!(hist.percentile(Channel::Red, 0.0), Some(100));
+    assert_eq!(hist.percentile(Channel::Red, 100.0), Some(150));
+
+    let empty = Statistics::from_region(&img, Rect::new(4, 4, 1, 1), Channel::Red);
+    assert!(empty.is_none());
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::{MatchMethod, TemplateMatch};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Pixel, Rect};
+
+use crate::common::noise_image;
+
+/// Copies a region of the image, `brightness` is added to every channel
+fn crop(img: &Image, area: Rect, brightness: i16) -> Image {
+    let mut res = Image::new(area.width, area.height);
+    for y in 0..area.height {
+        for x in 0..area.width {
+            let p = img.get_pixel(area.x + x, area.y + y).unwrap();
+            let shift = |v: u8| (v as i16 + brightness).clamp(0, 255) as u8;
+            res.set_pixel(x, y, Pixel { r: shift(p.r), g: shift(p.g), b: shift(p.b) });
+        }
+    }
+
+    res
+}
+
+#[test]
+fn test_best_match() {
+    let img = noise_image(60, 40, 3);
+    let area = Rect::new(31, 17, 12, 9);
+    let template = crop(&img, area, 0);
+
+    let ssd = TemplateMatch::new(&img, &template, MatchMethod::SquaredDifference);
+    assert_eq!(ssd.get_width(), 49);
+    assert_eq!(ssd.get_height(), 32);
+    assert_eq!(ssd.best().bounds, area);
+    assert!(ssd.best().score < 1e-3);
+
+    let ncc = TemplateMatch::new(&img, &template, MatchMethod::CrossCorrelation);
+    assert_eq!(ncc.best().bounds, area);
+    assert!((ncc.best().score - 1.0).abs() < 1e-4);
+
+    // Zero-mean correlation ignores the brightness shift
+    let brighter = crop(&img, area, -30);
+    let zncc = TemplateMatch::new(&img, &brighter, MatchMethod::ZeroMeanCrossCorrelation);
+    assert_eq!(zncc.best().bounds, area);
+    assert!(zncc.best().score > 0.95);
+}
+
+#[test]
+fn test_multiple_matches() {
+    let logo = noise_image(8, 6, 11);
+    let mut img = Image::new(50, 30);
+    for (ox, oy) in [(4, 3), (30, 20)] {
+        for y in 0..6 {
+            for x in 0..8 {
+                img.set_pixel(ox + x, oy + y, logo.get_pixel(x, y).unwrap().clone());
+            }
+        }
+    }
+
+    let result = TemplateMatch::new(&img, &logo, MatchMethod::ZeroMeanCrossCorrelation);
+    let matches = result.matches(0.9, 10);
+
+    assert_eq!(matches.len(), 2);
+    assert!(matches.iter().any(|m| m.bounds == Rect::new(4, 3, 8, 6)));
+    assert!(matches.iter().any(|m| m.bounds == Rect::new(30, 20, 8, 6)));
+    assert_eq!(result.to_image().get_width(), 43);
+}
+
+#[test]
+fn test_pyramid_locate() {
+    let img = noise_image(120, 90, 5);
+    let mut blurred = Image::new(120, 90);
+    // Smooth the noise a bit so the coarse levels keep some structure
+    for y in 0..90 {
+        for x in 0..120 {
+            let p = img.get_pixel(x / 4 * 4, y / 4 * 4).unwrap().clone();
+            blurred.set_pixel(x, y, p);
+        }
+    }
+
+    let area = Rect::new(53, 38, 24, 20);
+    let template = crop(&blurred, area, 0);
+
+    for method in [MatchMethod::SquaredDifference, MatchMethod::CrossCorrelation, MatchMethod::ZeroMeanCrossCorrelation] {
+        let location = TemplateMatch::locate(&blurred, &template, method, 2).unwrap();
+        assert_eq!(location.bounds, area);
+    }
+
+    assert!(TemplateMatch::locate(&template, &blurred, MatchMethod::SquaredDifference, 2).is_none());
+}
\ No newline at end of file
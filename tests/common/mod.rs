@@ -0,0 +1,38 @@
+// Also compiled into the standalone test targets, which use only some of the helpers
+#![allow(dead_code)]
+
+use rusty_imager::Image;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::Pixel;
+use rusty_imager::utils::Random;
+
+/// Gray pixel with all channels set to the value
+pub fn gray(value: u8) -> Pixel {
+    Pixel { r: value, g: value, b: value }
+}
+
+/// Image filled with a single gray value
+pub fn gray_image(width: usize, height: usize, value: u8) -> Image {
+    let mut img = Image::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            img.set_pixel(x, y, gray(value));
+        }
+    }
+
+    img
+}
+
+/// Image of random colors, the same seed gives the same image
+pub fn noise_image(width: usize, height: usize, seed: u64) -> Image {
+    let mut random = Random::new(seed);
+    let mut img = Image::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let r = random.below(256) as u8;
+            img.set_pixel(x, y, Pixel { r, g: 255 - r, b: random.below(256) as u8 });
+        }
+    }
+
+    img
+}
\ No newline at end of file
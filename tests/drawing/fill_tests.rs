@@ -0,0 +1,108 @@
+use rusty_imager::Image;
+use rusty_imager::drawing::{draw_rect, flood_fill, magic_wand, ColorTolerance};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Connectivity, Rect};
+use rusty_imager::utils::Random;
+
+use crate::common::gray;
+
+/// Reference selection with a plain breadth-first search
+fn naive_select(img: &Image, connectivity: Connectivity) -> Vec<bool> {
+    let (width, height) = (img.get_width(), img.get_height());
+    let seed = img.get_pixel(0, 0).cloned().unwrap();
+    let mut selected = vec![false; width * height];
+    let mut queue = vec![(0isize, 0isize)];
+    selected[0] = true;
+
+    while let Some((x, y)) = queue.pop() {
+        for (dx, dy) in connectivity.offsets() {
+            let (nx, ny) = (x + dx, y + dy);
+            if nx < 0 || ny < 0 || nx >= width as isize || ny >= height as isize {
+                continue;
+            }
+
+            let i = ny as usize * width + nx as usize;
+            if !selected[i] && img.get_pixel(nx as usize, ny as usize) == Some(&seed) {
+                selected[i] = true;
+                queue.push((nx, ny));
+            }
+        }
+    }
+
+    selected
+}
+
+#[test]
+fn test_connectivity() {
+    let mut img = Image::new(5, 5);
+    for i in 0..5 {
+        img.set_pixel(i, i, Colors::WHITE);
+    }
+
+    let count = |selection: Vec<bool>| selection.iter().filter(|&&s| s).count();
+    assert_eq!(count(magic_wand(&img, 0, 0, ColorTolerance::Rgb(0.0), Connectivity::Four)), 1);
+    assert_eq!(count(magic_wand(&img, 0, 0, ColorTolerance::Rgb(0.0), Connectivity::Eight)), 5);
+
+    // The black triangles touch only diagonally across the white line
+    assert_eq!(count(magic_wand(&img, 1, 0, ColorTolerance::Rgb(0.0), Connectivity::Four)), 10);
+    assert_eq!(count(magic_wand(&img, 1, 0, ColorTolerance::Rgb(0.0), Connectivity::Eight)), 20);
+}
+
+#[test]
+fn test_flood_fill_inside_outline() {
+    let mut img = Image::new(12, 12);
+    draw_rect(&mut img, Rect::new(2, 2, 8, 8), Colors::WHITE);
+
+    let filled = flood_fill(&mut img, 5, 5, Colors::RED, ColorTolerance::Rgb(0.0), Connectivity::Four);
+    assert_eq!(filled, 36);
+
+    for y in 0..12 {
+        for x in 0..12 {
+            let inside = (3..9).contains(&x) && (3..9).contains(&y);
+            assert_eq!(img.get_pixel(x, y) == Some(&Colors::RED), inside, "{} {}", x, y);
+        }
+    }
+
+    // Seeds outside of the image select nothing
+    assert_eq!(flood_fill(&mut img, 12, 0, Colors::RED, ColorTolerance::Rgb(0.0), Connectivity::Four), 0);
+}
+
+#[test]
+fn test_tolerance() {
+    let mut img = Image::new(8, 1);
+    for x in 0..8 {
+        img.set_pixel(x, 0, gray(x as u8 * 4));
+    }
+
+    let count = |tolerance| magic_wand(&img, 0, 0, tolerance, Connectivity::Four).iter().filter(|&&s| s).count();
+
+    // Every step of 4 adds about 6.9 in RGB and 1.1 in Lab near black
+    assert_eq!(count(ColorTolerance::Rgb(20.0)), 3);
+    assert_eq!(count(ColorTolerance::Rgb(6.0)), 1);
+    assert_eq!(count(ColorTolerance::Lab(1.0)), 1);
+    assert_eq!(count(ColorTolerance::Lab(3.0)), 3);
+
+    // Distances are measured from the seed, not between neighbours
+    assert_eq!(count(ColorTolerance::Rgb(1000.0)), 8);
+}
+
+#[test]
+fn test_matches_naive_search() {
+    let mut random = Random::new(11);
+
+    for connectivity in [Connectivity::Four, Connectivity::Eight] {
+        for _ in 0..20 {
+            let mut img = Image::new(23, 17);
+            for y in 0..17 {
+                for x in 0..23 {
+                    if random.below(100) < 40 {
+                        img.set_pixel(x, y, Colors::WHITE);
+                    }
+                }
+            }
+            img.set_pixel(0, 0, Colors::BLACK);
+
+            assert_eq!(magic_wand(&img, 0, 0, ColorTolerance::Rgb(0.0), connectivity), naive_select(&img, connectivity));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+mod shapes_tests;
+mod polygon_tests;
+mod text_tests;
+mod fill_tests;
\ No newline at end of file
@@ -0,0 +1,65 @@
+use rusty_imager::Image;
+use rusty_imager::drawing::{draw_cubic_bezier, draw_polygon, draw_quadratic_bezier, fill_polygon, FillRule};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Point};
+
+fn star() -> Vec<Point> {
+    vec![
+        Point::new(15.0, 1.0), Point::new(24.0, 28.0), Point::new(1.0, 11.0),
+        Point::new(29.0, 11.0), Point::new(6.0, 28.0)
+    ]
+}
+
+#[test]
+fn test_fill_rectangle_polygon() {
+    let mut img = Image::new(10, 10);
+    let square = [Point::new(2.0, 1.0), Point::new(6.0, 1.0), Point::new(6.0, 4.0), Point::new(2.0, 4.0)];
+    fill_polygon(&mut img, &square, FillRule::EvenOdd, Colors::WHITE);
+
+    for y in 0..10 {
+        for x in 0..10 {
+            let inside = (2..6).contains(&x) && (1..4).contains(&y);
+            assert_eq!(img.get_pixel(x, y) == Some(&Colors::WHITE), inside, "{} {}", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_fill_rules() {
+    let mut even_odd = Image::new(30, 30);
+    let mut non_zero = Image::new(30, 30);
+    fill_polygon(&mut even_odd, &star(), FillRule::EvenOdd, Colors::WHITE);
+    fill_polygon(&mut non_zero, &star(), FillRule::NonZero, Colors::WHITE);
+
+    // The pentagon in the middle is wound twice
+    assert_eq!(even_odd.get_pixel(15, 15), Some(&Colors::BLACK));
+    assert_eq!(non_zero.get_pixel(15, 15), Some(&Colors::WHITE));
+
+    // The tips are inside with both rules
+    assert_eq!(even_odd.get_pixel(15, 4), Some(&Colors::WHITE));
+    assert_eq!(non_zero.get_pixel(15, 4), Some(&Colors::WHITE));
+
+    let mut outline = Image::new(30, 30);
+    draw_polygon(&mut outline, &star(), Colors::WHITE);
+    assert_eq!(outline.get_pixel(15, 1), Some(&Colors::WHITE));
+    assert_eq!(outline.get_pixel(15, 15), Some(&Colors::BLACK));
+}
+
+#[test]
+fn test_bezier_curves() {
+    let mut img = Image::new(40, 40);
+    draw_quadratic_bezier(&mut img, Point::new(2.0, 30.0), Point::new(20.0, 0.0), Point::new(38.0, 30.0), Colors::WHITE);
+
+    assert_eq!(img.get_pixel(2, 30), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(38, 30), Some(&Colors::WHITE));
+    // The curve passes half way to the control point
+    assert_eq!(img.get_pixel(20, 15), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(20, 0), Some(&Colors::BLACK));
+
+    let mut img = Image::new(40, 40);
+    draw_cubic_bezier(&mut img, Point::new(0.0, 20.0), Point::new(10.0, 0.0), Point::new(30.0, 40.0), Point::new(39.0, 20.0), Colors::WHITE);
+
+    assert_eq!(img.get_pixel(0, 20), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(39, 20), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(20, 20), Some(&Colors::WHITE));
+}
\ No newline at end of file
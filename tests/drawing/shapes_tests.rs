@@ -0,0 +1,127 @@
+use rusty_imager::Image;
+use rusty_imager::drawing::{draw_circle, draw_ellipse, draw_line, draw_line_aa, draw_rect, draw_thick_line, fill_circle, fill_ellipse, fill_rect};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Pixel, Point, Rect};
+
+fn count(img: &Image, color: &Pixel) -> usize {
+    let mut res = 0;
+    for y in 0..img.get_height() {
+        for x in 0..img.get_width() {
+            if img.get_pixel(x, y) == Some(color) {
+                res += 1;
+            }
+        }
+    }
+
+    res
+}
+
+#[test]
+fn test_lines() {
+    let mut img = Image::new(20, 20);
+    draw_line(&mut img, Point::new(2.0, 3.0), Point::new(12.0, 8.0), Colors::WHITE);
+
+    assert_eq!(count(&img, &Colors::WHITE), 11);
+    assert_eq!(img.get_pixel(2, 3), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(12, 8), Some(&Colors::WHITE));
+
+    // Partially clipped lines are fine
+    draw_line(&mut img, Point::new(-5.0, 0.0), Point::new(5.0, 0.0), Colors::RED);
+    assert_eq!(count(&img, &Colors::RED), 6);
+
+    let mut thick = Image::new(20, 20);
+    draw_thick_line(&mut thick, Point::new(2.0, 10.0), Point::new(12.0, 10.0), 3.0, Colors::WHITE);
+    assert_eq!(count(&thick, &Colors::WHITE), 10 * 3);
+}
+
+#[test]
+fn test_antialiased_line() {
+    let mut img = Image::new(20, 20);
+    draw_line_aa(&mut img, Point::new(0.0, 5.0), Point::new(19.0, 5.0), Colors::WHITE);
+
+    // Axis aligned lines are fully covered in the middle
+    assert_eq!(img.get_pixel(10, 5), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(10, 6), Some(&Colors::BLACK));
+
+    let mut img = Image::new(20, 20);
+    draw_line_aa(&mut img, Point::new(0.0, 0.0), Point::new(19.0, 7.0), Colors::WHITE);
+
+    let partial = (0..20).flat_map(|y| (0..20).map(move |x| (x, y)))
+        .filter(|&(x, y)| {
+            let p = img.get_pixel(x, y).unwrap();
+            p.r > 0 && p.r < 255
+        })
+        .count();
+
+    assert!(partial > 10);
+}
+
+#[test]
+fn test_antialiased_line_fractional_ends() {
+    let mut img = Image::new(10, 10);
+    draw_line_aa(&mut img, Point::new(2.0, 5.0), Point::new(7.3, 5.0), Colors::WHITE);
+
+    // The start covers half of its pixel, the end reaches 0.8 into its pixel
+    assert_eq!(img.get_pixel(2, 5).unwrap().r, 128);
+    assert_eq!(img.get_pixel(5, 5).unwrap().r, 255);
+    assert_eq!(img.get_pixel(7, 5).unwrap().r, 204);
+    assert_eq!(img.get_pixel(8, 5).unwrap().r, 0);
+}
+
+#[test]
+fn test_antialiased_line_above_image() {
+    // Lines partly above the image are covered the same as the same line moved down
+    let mut above = Image::new(20, 10);
+    draw_line_aa(&mut above, Point::new(0.0, -0.3), Point::new(19.0, -0.3), Colors::WHITE);
+
+    let mut inside = Image::new(20, 10);
+    draw_line_aa(&mut inside, Point::new(0.0, 4.7), Point::new(19.0, 4.7), Colors::WHITE);
+
+    let row0 = above.get_pixel(10, 0).unwrap().r;
+    assert!((175..=182).contains(&row0), "{}", row0);
+    assert!(row0.abs_diff(inside.get_pixel(10, 5).unwrap().r) <= 1);
+    assert_eq!(above.get_pixel(10, 1).unwrap().r, 0);
+}
+
+#[test]
+fn test_rectangles() {
+    let mut img = Image::new(20, 20);
+    draw_rect(&mut img, Rect::new(2, 3, 5, 4), Colors::WHITE);
+    assert_eq!(count(&img, &Colors::WHITE), 14);
+    assert_eq!(img.get_pixel(4, 4), Some(&Colors::BLACK));
+
+    fill_rect(&mut img, Rect::new(15, 15, 10, 10), Colors::RED);
+    assert_eq!(count(&img, &Colors::RED), 25);
+}
+
+#[test]
+fn test_circles_and_ellipses() {
+    let mut outline = Image::new(30, 30);
+    let mut filled = Image::new(30, 30);
+    draw_circle(&mut outline, Point::new(15.0, 15.0), 8.0, Colors::WHITE);
+    fill_circle(&mut filled, Point::new(15.0, 15.0), 8.0, Colors::WHITE);
+
+    for y in 0..30 {
+        for x in 0..30 {
+            if outline.get_pixel(x, y) == Some(&Colors::WHITE) {
+                assert_eq!(filled.get_pixel(x, y), Some(&Colors::WHITE));
+            }
+        }
+    }
+
+    assert_eq!(outline.get_pixel(23, 15), Some(&Colors::WHITE));
+    assert_eq!(outline.get_pixel(15, 15), Some(&Colors::BLACK));
+    let area = count(&filled, &Colors::WHITE) as f32;
+    assert!((area - std::f32::consts::PI * 8.5 * 8.5).abs() < 15.0);
+
+    let mut ellipse = Image::new(30, 30);
+    draw_ellipse(&mut ellipse, Point::new(15.0, 15.0), 10.0, 4.0, Colors::WHITE);
+    for (x, y) in [(25, 15), (5, 15), (15, 19), (15, 11)] {
+        assert_eq!(ellipse.get_pixel(x, y), Some(&Colors::WHITE));
+    }
+    assert_eq!(ellipse.get_pixel(15, 20), Some(&Colors::BLACK));
+
+    fill_ellipse(&mut ellipse, Point::new(15.0, 15.0), 10.0, 4.0, Colors::RED);
+    assert_eq!(count(&ellipse, &Colors::WHITE), 0);
+    assert_eq!(ellipse.get_pixel(15, 15), Some(&Colors::RED));
+}
\ No newline at end of file
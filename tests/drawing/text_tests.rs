@@ -0,0 +1,344 @@
+use std::cell::Cell;
+
+use rusty_imager::Image;
+use rusty_imager::drawing::{draw_text, measure_text, BitmapFont, Font, Glyph, LineMetrics, TextAlign, TextStyle, TrueTypeFont};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Point};
+use rusty_imager::utils::Random;
+
+fn be16(values: &[i32]) -> Vec<u8> {
+    values.iter().flat_map(|&v| (v as u16).to_be_bytes()).collect()
+}
+
+/// Tables shared by the test fonts, `A` and `B` map to the glyphs 1 and 2
+fn metric_tables() -> Vec<(&'static [u8; 4], Vec<u8>)> {
+    let mut head = vec![0u8; 54];
+    head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+    head[36..44].copy_from_slice(&be16(&[0, 0, 1000, 500]));
+
+    let mut hhea = vec![0u8; 36];
+    hhea[4..10].copy_from_slice(&be16(&[800, -200, 0]));
+    hhea[34..36].copy_from_slice(&3u16.to_be_bytes());
+
+    vec![
+        (b"cmap", be16(&[0, 1, 3, 1, 0, 12, 4, 32, 0, 4, 4, 1, 0, 66, 0xFFFF, 0, 65, 0xFFFF, 1 - 65, 1, 0, 0])),
+        (b"head", head),
+        (b"hhea", hhea),
+        (b"hmtx", be16(&[500, 0, 600, 0, 1100, 0])),
+        (b"maxp", be16(&[0, 0x5000, 3]))
+    ]
+}
+
+/// Font file with the tables sorted by their tags
+fn font_file(version: &[u8; 4], mut tables: Vec<(&[u8; 4], Vec<u8>)>) -> Vec<u8> {
+    tables.sort_by_key(|(tag, _)| **tag);
+
+    let mut directory = [version.to_vec(), be16(&[tables.len() as i32, 0, 0, 0])].concat();
+    let mut data = vec![];
+    let mut offset = 12 + tables.len() * 16;
+
+    for (tag, table) in &tables {
+        directory.extend_from_slice(*tag);
+        directory.extend_from_slice(&[0; 4]);
+        directory.extend_from_slice(&(offset as u32).to_be_bytes());
+        directory.extend_from_slice(&(table.len() as u32).to_be_bytes());
+
+        data.extend_from_slice(table);
+        data.resize(data.len().next_multiple_of(4), 0);
+        offset = 12 + tables.len() * 16 + data.len();
+    }
+
+    [directory, data].concat()
+}
+
+/// Font with 1000 units per em, `A` is a 500 unit square on the baseline and `B` the same square shifted by a composite glyph
+fn test_font() -> Vec<u8> {
+    let square = [be16(&[1, 0, 0, 500, 500, 3, 0]), vec![1; 4], be16(&[0, 500, 0, -500, 0, 0, 500, 0])].concat();
+    let composite = be16(&[-1, 500, 0, 1000, 500, 0x0003, 1, 500, 0]);
+    let loca = be16(&[0, 0, square.len() as i32 / 2, (square.len() + composite.len()) as i32 / 2]);
+
+    let mut tables = metric_tables();
+    tables.push((b"glyf", [square, composite].concat()));
+    tables.push((b"loca", loca));
+
+    font_file(&[0, 1, 0, 0], tables)
+}
+
+/// CFF INDEX with two byte offsets
+fn cff_index(objects: &[Vec<u8>]) -> Vec<u8> {
+    if objects.is_empty() {
+        return vec![0, 0];
+    }
+
+    let mut offsets = vec![1];
+    for object in objects {
+        offsets.push(offsets.last().unwrap() + object.len() as i32);
+    }
+
+    [be16(&[objects.len() as i32]), vec![2], be16(&offsets), objects.concat()].concat()
+}
+
+/// Charstring or DICT operand stored in five bytes, so the offsets can be computed before the values are known
+fn cff_long(value: i32) -> Vec<u8> {
+    [vec![29], value.to_be_bytes().to_vec()].concat()
+}
+
+/// Charstring operand stored as a short integer
+fn cs(values: &[i32]) -> Vec<u8> {
+    values.iter().flat_map(|&v| [vec![28], (v as i16).to_be_bytes().to_vec()].concat()).collect()
+}
+
+/// OpenType font with CFF outlines and the metrics of `test_font`
+/// `A` is the same square drawn through a global subroutine, `B` is a circle of radius 250 made of four curves in a local subroutine
+/// * `cid` - CID-keyed font, the local subroutines come from the font dict selected for the glyph
+fn cff_test_font(cid: bool) -> Vec<u8> {
+    // Advance width 600, one horizontal stem and its hint mask, then the square
+    let square = [cs(&[600, 0, 500]), vec![1, 19, 0x80], cs(&[0, 0]), vec![21], cs(&[-107]), vec![29, 14]].concat();
+    let circle = [cs(&[500, 250]), vec![21], cs(&[-107]), vec![10, 14]].concat();
+    let quarters = [cs(&[0, 138, -112, 112, -138, 0]), vec![8], cs(&[-138, -112, -112, -138]), vec![31], cs(&[-138, 112, -112, 138, 138, 112, 112, 138]), vec![30]];
+    let global_subrs = cff_index(&[[cs(&[500, 500, -500]), vec![6, 11]].concat()]);
+    let local_subrs = cff_index(&[[quarters.concat(), vec![11]].concat()]);
+    let char_strings = cff_index(&[vec![14], square, circle]);
+
+    let header = vec![1, 0, 4, 2];
+    let names = cff_index(&[b"Test".to_vec()]);
+    let private = [cff_long(6), vec![19]].concat();
+    let private_entry = |offset: usize| [cff_long(private.len() as i32), cff_long(offset as i32), vec![18]].concat();
+
+    // One font dict used by all glyphs (selector format 3 with a single range)
+    let fd_array_size = if cid { cff_index(&[private_entry(0)]).len() } else { 0 };
+    let fd_select = if cid { vec![3, 0, 1, 0, 0, 0, 0, 3] } else { vec![] };
+
+    // Offsets are five byte operands, so the size of the top dict does not depend on them
+    let top = |char_strings_offset: usize, fd_array_offset: usize, private_offset: usize| {
+        if cid {
+            let ros = vec![139, 139, 139, 12, 30];
+            let fd = [cff_long(fd_array_offset as i32), vec![12, 36], cff_long((fd_array_offset + fd_array_size) as i32), vec![12, 37]].concat();
+            [ros, cff_long(char_strings_offset as i32), vec![17], fd].concat()
+        } else {
+            [cff_long(char_strings_offset as i32), vec![17], private_entry(private_offset)].concat()
+        }
+    };
+
+    let char_strings_offset = header.len() + names.len() + cff_index(&[top(0, 0, 0)]).len() + 2 + global_subrs.len();
+    let fd_array_offset = char_strings_offset + char_strings.len();
+    let private_offset = fd_array_offset + fd_array_size + fd_select.len();
+    let fd_array = if cid { cff_index(&[private_entry(private_offset)]) } else { vec![] };
+
+    let top = top(char_strings_offset, fd_array_offset, private_offset);
+    let cff = [header, names, cff_index(&[top]), cff_index(&[]), global_subrs, char_strings, fd_array, fd_select, private, local_subrs].concat();
+
+    let mut tables = metric_tables();
+    tables.push((b"CFF ", cff));
+
+    font_file(b"OTTO", tables)
+}
+
+/// Bitmap font counting how many glyphs were rasterized
+struct CountingFont {
+    rasterized: Cell<usize>
+}
+
+impl Font for CountingFont {
+    fn glyph(&self, ch: char, size: f32) -> Glyph {
+        self.rasterized.set(self.rasterized.get() + 1);
+        BitmapFont::new().glyph(ch, size)
+    }
+
+    fn line_metrics(&self, size: f32) -> LineMetrics {
+        BitmapFont::new().line_metrics(size)
+    }
+
+    fn advance(&self, ch: char, size: f32) -> f32 {
+        BitmapFont::new().advance(ch, size)
+    }
+}
+
+/// Offset of the table with the given tag
+fn table_offset(font: &[u8], tag: &[u8; 4]) -> usize {
+    let count = u16::from_be_bytes([font[4], font[5]]) as usize;
+    let record = (0..count).map(|i| 12 + i * 16).find(|&r| &font[r..r + 4] == tag).unwrap();
+
+    u32::from_be_bytes(font[record + 8..record + 12].try_into().unwrap()) as usize
+}
+
+#[test]
+fn test_bitmap_font_glyph() {
+    let font = BitmapFont::new();
+    let glyph = font.glyph('A', 8.0);
+
+    assert_eq!((glyph.width, glyph.height, glyph.top), (8, 8, -7));
+    assert_eq!(glyph.advance, 8.0);
+    assert!(glyph.coverage.iter().all(|&c| c == 0.0 || c == 1.0));
+    assert!(glyph.coverage.contains(&1.0));
+
+    // Characters outside of ASCII fall back to the question mark
+    assert_eq!(font.glyph('\u{2603}', 8.0), font.glyph('?', 8.0));
+
+    let scaled = font.glyph('A', 16.0);
+    assert_eq!((scaled.width, scaled.height, scaled.advance), (16, 16, 16.0));
+}
+
+#[test]
+fn test_draw_text_bitmap() {
+    let mut img = Image::new(10, 10);
+    draw_text(&mut img, "-", Point::new(0.0, 0.0), &BitmapFont::new(), &TextStyle::new(8.0, Colors::WHITE));
+
+    // The dash is the fourth row of the glyph, six pixels wide
+    for y in 0..10 {
+        for x in 0..10 {
+            let lit = y == 3 && x < 6;
+            assert_eq!(img.get_pixel(x, y) == Some(&Colors::WHITE), lit, "{} {}", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_measure_and_align() {
+    let font = BitmapFont::new();
+    assert_eq!(measure_text("ab\nc", &font, 8.0), (16.0, 17.0));
+
+    let mut img = Image::new(30, 10);
+    let style = TextStyle::new(8.0, Colors::WHITE).with_align(TextAlign::Right);
+    draw_text(&mut img, "-", Point::new(20.0, 0.0), &font, &style);
+
+    for x in 0..30 {
+        assert_eq!(img.get_pixel(x, 3) == Some(&Colors::WHITE), (12..18).contains(&x), "{}", x);
+    }
+
+    let mut centered = Image::new(30, 10);
+    draw_text(&mut centered, "-", Point::new(20.0, 0.0), &font, &style.with_align(TextAlign::Center));
+    assert_eq!(centered.get_pixel(16, 3), Some(&Colors::WHITE));
+    assert_eq!(centered.get_pixel(22, 3), Some(&Colors::BLACK));
+}
+
+#[test]
+fn test_truetype_metrics() {
+    let font = TrueTypeFont::parse(test_font()).expect("Invalid font");
+    let metrics = font.line_metrics(20.0);
+
+    assert_eq!((metrics.ascent, metrics.descent, metrics.line_gap), (16.0, 4.0, 0.0));
+    assert_eq!(font.glyph_index('A'), 1);
+    assert_eq!(font.glyph_index('B'), 2);
+    assert_eq!(font.glyph_index('C'), 0);
+
+    // The missing glyph has no outline but keeps its advance
+    let missing = font.glyph('C', 20.0);
+    assert_eq!((missing.width, missing.advance), (0, 10.0));
+
+    assert!(TrueTypeFont::parse(vec![0; 16]).is_none());
+
+}
+
+#[test]
+fn test_truetype_glyphs() {
+    let font = TrueTypeFont::parse(test_font()).expect("Invalid font");
+
+    let square = font.glyph('A', 20.0);
+    assert_eq!((square.left, square.top, square.advance), (0, -10, 12.0));
+
+    let composite = font.glyph('B', 20.0);
+    assert_eq!((composite.left, composite.top, composite.advance), (10, -10, 22.0));
+    assert_eq!(composite.coverage, square.coverage);
+
+    let mut img = Image::new(20, 20);
+    draw_text(&mut img, "A", Point::new(2.0, 0.0), &font, &TextStyle::new(20.0, Colors::WHITE));
+
+    for y in 0..20 {
+        for x in 0..20 {
+            let inside = (2..12).contains(&x) && (6..16).contains(&y);
+            let expected = if inside { Colors::WHITE } else { Colors::BLACK };
+            assert_eq!(img.get_pixel(x, y), Some(&expected), "{} {}", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_cff_glyphs() {
+    let truetype = TrueTypeFont::parse(test_font()).expect("Invalid font");
+
+    for cid in [false, true] {
+        let font = TrueTypeFont::parse(cff_test_font(cid)).expect("Invalid font");
+        check_cff_font(&font, &truetype);
+    }
+}
+
+fn check_cff_font(font: &TrueTypeFont, truetype: &TrueTypeFont) {
+    // The same square as the TrueType outline, the width in the charstring is ignored
+    assert_eq!(font.glyph('A', 20.0), truetype.glyph('A', 20.0));
+    assert_eq!(font.line_metrics(20.0), truetype.line_metrics(20.0));
+
+    let circle = font.glyph('B', 20.0);
+    let area: f32 = circle.coverage.iter().sum();
+    assert_eq!((circle.left, circle.top, circle.width, circle.height), (0, -10, 11, 11));
+    assert!((area - 25.0 * std::f32::consts::PI).abs() < 1.0, "{}", area);
+    assert_eq!(circle.coverage[5 * 11 + 5], 1.0);
+    assert_eq!(circle.coverage[0], 0.0);
+
+    let missing = font.glyph('C', 20.0);
+    assert_eq!((missing.width, missing.advance), (0, 10.0));
+}
+
+#[test]
+fn test_truetype_broken_outline() {
+    // The second point of the square is moved far outside of the font bounding box
+    let mut data = test_font();
+    let glyf = table_offset(&data, b"glyf");
+    data[glyf + 20..glyf + 22].copy_from_slice(&i16::MAX.to_be_bytes());
+
+    let font = TrueTypeFont::parse(data).expect("Invalid font");
+    let glyph = font.glyph('A', 20.0);
+    assert!(glyph.width <= 21 && glyph.height <= 11, "{}x{}", glyph.width, glyph.height);
+
+    // Units per em outside of the allowed range are rejected
+    let mut data = test_font();
+    let head = table_offset(&data, b"head");
+    data[head + 18..head + 20].copy_from_slice(&8u16.to_be_bytes());
+    assert!(TrueTypeFont::parse(data).is_none());
+}
+
+#[test]
+fn test_truetype_corrupted_fonts() {
+    let mut random = Random::new(5);
+
+    for original in [test_font(), cff_test_font(false), cff_test_font(true)] {
+        let head = table_offset(&original, b"head");
+
+        for _ in 0..2000 {
+            let mut data = original.clone();
+            for _ in 0..1 + random.below(4) {
+                let pos = random.below(data.len());
+                if !(head..head + 54).contains(&pos) {
+                    data[pos] = random.next_u64() as u8;
+                }
+            }
+
+            let Some(font) = TrueTypeFont::parse(data) else {
+                continue;
+            };
+
+            for ch in ['A', 'B', 'C'] {
+                let glyph = font.glyph(ch, 20.0);
+                assert!(glyph.width * glyph.height <= 16 * 22 * 22);
+                assert_eq!(glyph.coverage.len(), glyph.width * glyph.height);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_glyphs_rasterized_once() {
+    for align in [TextAlign::Left, TextAlign::Center, TextAlign::Right] {
+        let font = CountingFont { rasterized: Cell::new(0) };
+        let mut img = Image::new(40, 20);
+        draw_text(&mut img, "ab\ncd", Point::new(20.0, 0.0), &font, &TextStyle::new(8.0, Colors::WHITE).with_align(align));
+
+        assert_eq!(font.rasterized.get(), 4, "{:?}", align);
+    }
+
+    let truetype = TrueTypeFont::parse(test_font()).expect("Invalid font");
+    for ch in ['A', 'B', 'C'] {
+        assert_eq!(truetype.advance(ch, 20.0), truetype.glyph(ch, 20.0).advance);
+        assert_eq!(BitmapFont::new().advance(ch, 12.0), BitmapFont::new().glyph(ch, 12.0).advance);
+    }
+}
\ No newline at end of file
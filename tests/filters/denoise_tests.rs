@@ -0,0 +1,77 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::Statistics;
+use rusty_imager::filters::{Bilateral, ImageFilter, Median, NonLocalMeans};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Channel, Pixel, Rect};
+use rusty_imager::utils::Random;
+
+/// Dark left half, bright right half, with added noise
+fn noisy_step(amplitude: f32) -> Image {
+    let mut random = Random::new(7);
+    let mut img = Image::new(24, 24);
+
+    for x in 0..24 {
+        for y in 0..24 {
+            let base = if x < 12 { 60.0 } else { 190.0 };
+            let value = (base + (random.next_f32() - 0.5) * 2.0 * amplitude).round() as u8;
+            img.set_pixel(x, y, Pixel { r: value, g: value, b: value });
+        }
+    }
+
+    img
+}
+
+fn check_denoiser(filter: impl ImageFilter) {
+    let mut img = noisy_step(30.0);
+    let flat = Rect::new(2, 2, 8, 20);
+    let before = Statistics::from_region(&img, flat, Channel::Red).unwrap();
+
+    img.apply_filter(filter);
+
+    let after = Statistics::from_region(&img, flat, Channel::Red).unwrap();
+    assert!(after.std_dev < before.std_dev / 2.0);
+
+    // The edge stays sharp
+    assert!(img.get_pixel(10, 12).unwrap().r < 100);
+    assert!(img.get_pixel(13, 12).unwrap().r > 150);
+}
+
+#[test]
+fn test_median_removes_salt_and_pepper() {
+    let mut img = noisy_step(0.0);
+    img.set_pixel(4, 4, Pixel { r: 255, g: 255, b: 255 });
+    img.set_pixel(16, 7, Pixel { r: 0, g: 0, b: 0 });
+
+    img.apply_filter(Median::new(1));
+
+    assert_eq!(img.get_pixel(4, 4).unwrap().r, 60);
+    assert_eq!(img.get_pixel(16, 7).unwrap().r, 190);
+    assert_eq!(img.get_pixel(11, 7).unwrap().r, 60);
+}
+
+#[test]
+fn test_median() {
+    check_denoiser(Median::new(2));
+}
+
+#[test]
+fn test_bilateral() {
+    check_denoiser(Bilateral::new(2.0, 40.0));
+}
+
+#[test]
+fn test_non_local_means() {
+    check_denoiser(NonLocalMeans::new(25.0, 1, 4));
+}
+
+#[test]
+fn test_denoise_empty_images() {
+    for (width, height) in [(0, 5), (5, 0), (0, 0)] {
+        let mut img = Image::new(width, height);
+        img.apply_filter(Median::new(1));
+        img.apply_filter(Bilateral::new(2.0, 40.0));
+        img.apply_filter(NonLocalMeans::new(25.0, 1, 4));
+
+        assert_eq!((img.get_width(), img.get_height()), (width, height));
+    }
+}
\ No newline at end of file
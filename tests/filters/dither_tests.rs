@@ -0,0 +1,85 @@
+use rusty_imager::Image;
+use rusty_imager::filters::{DiffusionKernel, ErrorDiffusion, OrderedDither};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::Colors;
+use rusty_imager::utils::Random;
+
+use crate::common::gray_image;
+
+fn white_ratio(img: &Image) -> f32 {
+    let mut white = 0;
+    for x in 0..img.get_width() {
+        for y in 0..img.get_height() {
+            let pixel = img.get_pixel(x, y).unwrap();
+            assert!(*pixel == Colors::WHITE || *pixel == Colors::BLACK);
+
+            if *pixel == Colors::WHITE {
+                white += 1;
+            }
+        }
+    }
+
+    white as f32 / (img.get_width() * img.get_height()) as f32
+}
+
+#[test]
+fn test_error_diffusion_preserves_tone() {
+    let kernels = [
+        DiffusionKernel::FloydSteinberg,
+        DiffusionKernel::JarvisJudiceNinke,
+        DiffusionKernel::Stucki,
+        DiffusionKernel::Sierra
+    ];
+
+    for kernel in kernels {
+        let mut img = gray_image(32, 32, 64);
+        img.apply_filter(ErrorDiffusion::new(kernel, vec![Colors::BLACK, Colors::WHITE], true));
+
+        assert!((white_ratio(&img) - 0.25).abs() < 0.03, "{:?}", kernel);
+    }
+}
+
+#[test]
+fn test_atkinson_diffusion() {
+    // Only 3/4 of the error is propagated, so the tones drift to the extremes by at most 1/8 of the range
+    for value in [16, 64, 128, 192, 240] {
+        let mut img = gray_image(32, 32, value);
+        img.apply_filter(ErrorDiffusion::new(DiffusionKernel::Atkinson, vec![Colors::BLACK, Colors::WHITE], true));
+
+        let ratio = white_ratio(&img);
+        assert!((ratio - value as f32 / 255.0).abs() <= 0.13, "{} {}", value, ratio);
+    }
+
+    // Shadows are clipped, the midtone stays balanced
+    let mut shadow = gray_image(32, 32, 16);
+    shadow.apply_filter(ErrorDiffusion::new(DiffusionKernel::Atkinson, vec![Colors::BLACK, Colors::WHITE], false));
+    assert_eq!(white_ratio(&shadow), 0.0);
+
+    let mut midtone = gray_image(32, 32, 128);
+    midtone.apply_filter(ErrorDiffusion::new(DiffusionKernel::Atkinson, vec![Colors::BLACK, Colors::WHITE], false));
+    assert!((white_ratio(&midtone) - 0.5).abs() < 0.02);
+}
+
+#[test]
+fn test_bayer_pattern() {
+    let mut img = gray_image(32, 32, 128);
+    img.apply_filter(OrderedDither::bayer(4, vec![Colors::BLACK, Colors::WHITE]));
+
+    assert_eq!(white_ratio(&img), 0.5);
+    assert_ne!(img.get_pixel(0, 0), img.get_pixel(1, 0));
+}
+
+#[test]
+fn test_blue_noise_preserves_tone() {
+    let mut img = gray_image(32, 32, 192);
+    img.apply_filter(OrderedDither::blue_noise(vec![Colors::BLACK, Colors::WHITE]));
+
+    assert!((white_ratio(&img) - 0.75).abs() < 0.02);
+}
+
+#[test]
+fn test_no_degenerate_seed() {
+    // The seed that cancels the internal seed mixing must still give random numbers
+    let mut random = Random::new(0x9E37_79B9_7F4A_7C15);
+    assert!((0..4).any(|_| random.next_u64() != 0));
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::{Gradient, GradientOperator};
+use rusty_imager::filters::{Canny, EdgeDetection, EdgeOperator};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Pixel};
+
+/// Bright square in the middle of a dark image
+fn square_image() -> Image {
+    let mut img = Image::new(20, 20);
+    for x in 0..20 {
+        for y in 0..20 {
+            if (6..14).contains(&x) && (6..14).contains(&y) {
+                img.set_pixel(x, y, Pixel { r: 40, g: 220, b: 40 });
+            }
+        }
+    }
+
+    img
+}
+
+#[test]
+fn test_operators_find_square_border() {
+    let operators = [
+        EdgeOperator::Sobel,
+        EdgeOperator::Scharr,
+        EdgeOperator::Prewitt,
+        EdgeOperator::Roberts,
+        EdgeOperator::LaplacianOfGaussian { sigma: 1.0 }
+    ];
+
+    for operator in operators {
+        let mut img = square_image();
+        img.apply_filter(EdgeDetection::with_operator(operator, 0.2, 1.0));
+
+        let border = (5..=6).any(|x| img.get_pixel(x, 10).unwrap().r > 0);
+        assert!(border, "{:?}", operator);
+        assert_eq!(img.get_pixel(1, 1), Some(&Colors::BLACK), "{:?}", operator);
+        assert_eq!(img.get_pixel(10, 10), Some(&Colors::BLACK), "{:?}", operator);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_laplacian_of_gaussian_zero_sigma() {
+    EdgeDetection::with_operator(EdgeOperator::LaplacianOfGaussian { sigma: 0.0 }, 0.2, 1.0);
+}
+
+#[test]
+fn test_canny_edges_are_thin() {
+    let img = square_image();
+    let edges = Canny::new(1.0, 0.1, 0.3).detect(&img);
+
+    let row: Vec<bool> = (0..20).map(|x| edges[10 * 20 + x]).collect();
+    assert_eq!(row.iter().filter(|&&e| e).count(), 2);
+    assert!(!edges[10 * 20 + 10]);
+}
+
+#[test]
+fn test_gradient_images() {
+    let img = square_image();
+    let gradient = Gradient::from_image(&img, GradientOperator::Sobel);
+
+    // Luminance rises to the right on the left border of the square
+    assert!(gradient.x()[10 * 20 + 6] > 0.0);
+    assert_eq!(gradient.y()[10 * 20 + 6], 0.0);
+
+    let magnitude = gradient.magnitude_image();
+    assert_eq!(magnitude.get_width(), 20);
+    assert_eq!(magnitude.get_pixel(1, 1), Some(&Colors::BLACK));
+
+    let direction = gradient.direction_image();
+    assert_eq!(direction.get_pixel(6, 10).unwrap().r, 128);
+}
\ No newline at end of file
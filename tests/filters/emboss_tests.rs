@@ -0,0 +1,46 @@
+use rusty_imager::Image;
+use rusty_imager::filters::Emboss;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::Pixel;
+
+/// Dark left half, bright right half
+fn step_image() -> Image {
+    let mut img = Image::new(10, 10);
+    for x in 0..10 {
+        for y in 0..10 {
+            let value = if x < 5 { 50 } else { 200 };
+            img.set_pixel(x, y, Pixel { r: value, g: value / 2, b: value });
+        }
+    }
+
+    img
+}
+
+#[test]
+fn test_emboss_does_not_panic() {
+    let mut img = step_image();
+    img.apply_filter(Emboss::default());
+}
+
+#[test]
+fn test_emboss_light_direction() {
+    let mut lit = step_image();
+    lit.apply_filter(Emboss::new(180.0, 45.0, 2.0, false));
+
+    let mut shadowed = step_image();
+    shadowed.apply_filter(Emboss::new(0.0, 45.0, 2.0, false));
+
+    let flat = lit.get_pixel(1, 5).unwrap().r;
+    assert_eq!(flat, shadowed.get_pixel(1, 5).unwrap().r);
+    assert!(lit.get_pixel(5, 5).unwrap().r > flat);
+    assert!(shadowed.get_pixel(5, 5).unwrap().r < flat);
+}
+
+#[test]
+fn test_colored_emboss_keeps_flat_colors() {
+    let mut img = step_image();
+    img.apply_filter(Emboss::new(135.0, 30.0, 1.0, true));
+
+    assert_eq!(img.get_pixel(1, 5), Some(&Pixel { r: 50, g: 25, b: 50 }));
+    assert_eq!(img.get_pixel(8, 5), Some(&Pixel { r: 200, g: 100, b: 200 }));
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::Statistics;
+use rusty_imager::filters::{Clahe, HistogramEqualization};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Channel, Pixel};
+
+fn low_contrast_image() -> Image {
+    let mut img = Image::new(32, 32);
+
+    for x in 0..32 {
+        for y in 0..32 {
+            let value = 100 + ((x + y) % 16) as u8;
+            img.set_pixel(x, y, Pixel { r: value, g: value, b: value });
+        }
+    }
+
+    img
+}
+
+#[test]
+fn test_equalization_stretches_range() {
+    let mut img = low_contrast_image();
+    img.apply_filter(HistogramEqualization::new(false));
+
+    let stats = Statistics::from_image(&img, Channel::Red).unwrap();
+    assert!(stats.min < 20);
+    assert_eq!(stats.max, 255);
+}
+
+#[test]
+fn test_clahe_increases_contrast() {
+    let mut img = low_contrast_image();
+    let before = Statistics::from_image(&img, Channel::Luminance).unwrap();
+
+    img.apply_filter(Clahe::new(4, 4.0, true));
+
+    let after = Statistics::from_image(&img, Channel::Luminance).unwrap();
+    assert!(after.std_dev > before.std_dev * 2.0);
+
+    let pixel = img.get_pixel(5, 7).unwrap();
+    assert_eq!(pixel.r, pixel.g);
+    assert_eq!(pixel.g, pixel.b);
+}
+
+#[test]
+fn test_clahe_uneven_grid() {
+    // Every column has its own brightness, a grid of 8 tiles on 9 columns leaves most tiles one column wide
+    let mut img = Image::new(9, 4);
+    for x in 0..9 {
+        for y in 0..4 {
+            let value = 50 + 10 * x as u8;
+            img.set_pixel(x, y, Pixel { r: value, g: value, b: value });
+        }
+    }
+
+    img.apply_filter(Clahe::new(8, 4.0, false));
+
+    // A pixel in the center of a one column tile is the brightest value of that tile
+    for x in (0..7).chain([8]) {
+        assert_eq!(img.get_pixel(x, 2).unwrap().r, 255, "{}", x);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+mod equalization_tests;
+mod threshold_tests;
+mod white_balance_tests;
+mod dither_tests;
+mod emboss_tests;
+mod edge_tests;
+mod denoise_tests;
+mod sharpen_tests;
+mod morphology_tests;
+mod noise_tests;
\ No newline at end of file
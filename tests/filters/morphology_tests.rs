@@ -0,0 +1,98 @@
+use rusty_imager::Image;
+use rusty_imager::filters::{MorphOperation, Morphology, StructuringElement};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::Colors;
+
+/// White 8x8 square with a one pixel dark gap and a single white speck outside of it
+fn scan_image() -> Image {
+    let mut img = Image::new(20, 20);
+    for x in 4..12 {
+        for y in 4..12 {
+            if x != 8 {
+                img.set_pixel(x, y, Colors::WHITE);
+            }
+        }
+    }
+
+    img.set_pixel(16, 16, Colors::WHITE);
+    img
+}
+
+fn white_count(img: &Image) -> usize {
+    let mut count = 0;
+    for x in 0..img.get_width() {
+        for y in 0..img.get_height() {
+            if *img.get_pixel(x, y).unwrap() == Colors::WHITE {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+#[test]
+fn test_open_removes_specks() {
+    let mut img = scan_image();
+    img.apply_filter(Morphology::new(MorphOperation::Open, StructuringElement::rect(3, 1), 1));
+
+    assert_eq!(img.get_pixel(16, 16), Some(&Colors::BLACK));
+    assert_eq!(img.get_pixel(5, 5), Some(&Colors::WHITE));
+}
+
+#[test]
+fn test_close_fills_gaps() {
+    let mut img = scan_image();
+    img.apply_filter(Morphology::new(MorphOperation::Close, StructuringElement::cross(3, 3), 1));
+
+    assert_eq!(img.get_pixel(8, 6), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(16, 16), Some(&Colors::WHITE));
+}
+
+#[test]
+fn test_erode_iterations() {
+    let mut img = scan_image();
+    img.apply_filter(Morphology::new(MorphOperation::Erode, StructuringElement::rect(3, 3), 1));
+    assert_eq!(white_count(&img), (2 + 1) * 6);
+
+    let mut img = scan_image();
+    img.apply_filter(Morphology::new(MorphOperation::Dilate, StructuringElement::rect(3, 3), 2));
+    assert_eq!(img.get_pixel(2, 2), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(1, 1), Some(&Colors::BLACK));
+}
+
+#[test]
+fn test_top_hat_and_gradient() {
+    let mut img = scan_image();
+    img.apply_filter(Morphology::new(MorphOperation::TopHat, StructuringElement::rect(3, 3), 1));
+    assert_eq!(white_count(&img), 1);
+
+    let mut img = scan_image();
+    img.apply_filter(Morphology::new(MorphOperation::Gradient, StructuringElement::rect(3, 3), 1));
+    assert_eq!(img.get_pixel(4, 6), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(0, 0), Some(&Colors::BLACK));
+
+    let mut img = scan_image();
+    img.apply_filter(Morphology::new(MorphOperation::BlackHat, StructuringElement::rect(3, 3), 1));
+    assert_eq!(img.get_pixel(8, 6), Some(&Colors::WHITE));
+    assert_eq!(img.get_pixel(5, 6), Some(&Colors::BLACK));
+}
+
+#[test]
+fn test_ellipse_elements() {
+    assert_eq!(StructuringElement::ellipse(3, 3), StructuringElement::cross(3, 3));
+    assert_eq!(StructuringElement::ellipse(1, 1), StructuringElement::rect(1, 1));
+
+    let mask = vec![
+        false, false, true, false, false,
+        true,  true,  true, true,  true,
+        true,  true,  true, true,  true,
+        true,  true,  true, true,  true,
+        false, false, true, false, false
+    ];
+    assert_eq!(StructuringElement::ellipse(5, 5), StructuringElement::custom(5, 5, mask));
+
+    // Wide elements keep the rows within the width
+    let wide = StructuringElement::ellipse(7, 3);
+    assert_eq!(wide, StructuringElement::custom(7, 3, (0..21).map(|idx| idx / 7 == 1 || idx % 7 == 3).collect()));
+}
\ No newline at end of file
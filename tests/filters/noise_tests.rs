@@ -0,0 +1,61 @@
+use rusty_imager::analysis::Statistics;
+use rusty_imager::filters::{GaussianNoise, SaltAndPepper, UniformNoise};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Channel, Colors};
+
+use crate::common::gray_image;
+
+#[test]
+fn test_gaussian_noise() {
+    let mut img = gray_image(64, 64, 128);
+    img.apply_filter(GaussianNoise::new(10.0, 1));
+
+    let stats = Statistics::from_image(&img, Channel::Green).unwrap();
+    assert!((stats.mean - 128.0).abs() < 1.0);
+    assert!((stats.std_dev - 10.0).abs() < 1.0);
+
+    // Channels get independent noise
+    assert!((0..64).any(|x| {
+        let pixel = img.get_pixel(x, 0).unwrap();
+        pixel.r != pixel.g
+    }));
+
+    let mut again = gray_image(64, 64, 128);
+    again.apply_filter(GaussianNoise::new(10.0, 1));
+    assert_eq!(img.to_bytes(), again.to_bytes());
+}
+
+#[test]
+fn test_uniform_noise() {
+    let mut img = gray_image(64, 64, 100);
+    img.apply_filter(UniformNoise::new(20.0, 2));
+
+    let stats = Statistics::from_image(&img, Channel::Blue).unwrap();
+    assert!(stats.min >= 80 && stats.max <= 120);
+    assert!(stats.min <= 82 && stats.max >= 118);
+
+    // Standard deviation of a uniform distribution is the amplitude divided by the square root of 3
+    assert!((stats.std_dev - 20.0 / 3f32.sqrt()).abs() < 1.0);
+}
+
+#[test]
+fn test_salt_and_pepper() {
+    let mut img = gray_image(64, 64, 128);
+    img.apply_filter(SaltAndPepper::new(0.2, 3));
+
+    let mut salt = 0;
+    let mut pepper = 0;
+    for y in 0..64 {
+        for x in 0..64 {
+            match img.get_pixel(x, y).unwrap() {
+                p if *p == Colors::WHITE => salt += 1,
+                p if *p == Colors::BLACK => pepper += 1,
+                p => assert_eq!(p.r, 128)
+            }
+        }
+    }
+
+    let total = 64 * 64;
+    assert!((salt as f32 / total as f32 - 0.1).abs() < 0.02);
+    assert!((pepper as f32 / total as f32 - 0.1).abs() < 0.02);
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::Statistics;
+use rusty_imager::filters::{HighPass, UnsharpMask};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Channel, Pixel};
+
+/// Soft ramp from dark to bright in the middle
+fn ramp_image() -> Image {
+    let mut img = Image::new(20, 4);
+    for x in 0..20 {
+        for y in 0..4 {
+            let value = (80 + (x as i32 - 8).clamp(0, 4) * 20) as u8;
+            img.set_pixel(x, y, Pixel { r: value, g: value, b: value });
+        }
+    }
+
+    img
+}
+
+#[test]
+fn test_unsharp_mask_keeps_brightness() {
+    let mut img = ramp_image();
+    let before = Statistics::from_image(&img, Channel::Red).unwrap();
+
+    img.apply_filter(UnsharpMask::new(1.0, 1.0, 0));
+
+    let after = Statistics::from_image(&img, Channel::Red).unwrap();
+    assert!((after.mean - before.mean).abs() < 1.0);
+    assert!(after.min < before.min);
+    assert!(after.max > before.max);
+    assert_eq!(img.get_pixel(1, 1).unwrap().r, 80);
+}
+
+#[test]
+fn test_unsharp_mask_threshold() {
+    let mut img = ramp_image();
+    img.apply_filter(UnsharpMask::new(1.0, 1.0, 100));
+
+    assert_eq!(Statistics::from_image(&img, Channel::Red).unwrap().min, 80);
+}
+
+#[test]
+fn test_high_pass() {
+    let mut img = ramp_image();
+    img.apply_filter(HighPass::new(1.0));
+
+    assert_eq!(img.get_pixel(1, 1).unwrap().r, 128);
+    assert!(img.get_pixel(8, 1).unwrap().r < 128);
+    assert!(img.get_pixel(12, 1).unwrap().r > 128);
+}
\ No newline at end of file
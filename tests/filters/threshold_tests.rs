@@ -0,0 +1,104 @@
+use rusty_imager::Image;
+use rusty_imager::analysis::Histogram;
+use rusty_imager::filters::{AdaptiveMethod, AdaptiveThreshold, Threshold};
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Channel, Colors};
+
+use crate::common::gray;
+
+#[test]
+fn test_otsu_separates_modes() {
+    let mut img = Image::new(10, 10);
+    for x in 0..10 {
+        for y in 0..10 {
+            img.set_pixel(x, y, gray(if x < 5 { 40 + y as u8 } else { 200 - y as u8 }));
+        }
+    }
+
+    let cutoff = Histogram::from_image(&img).otsu_threshold(Channel::Luminance).unwrap();
+    assert!((49..191).contains(&cutoff));
+
+    img.apply_filter(Threshold::otsu());
+    assert_eq!(img.get_pixel(2, 2), Some(&Colors::BLACK));
+    assert_eq!(img.get_pixel(7, 2), Some(&Colors::WHITE));
+}
+
+/// Background gets brighter from left to right, "ink" is always 40 below it
+fn uneven_lighting() -> Image {
+    let mut img = Image::new(60, 20);
+    for x in 0..60 {
+        for y in 0..20 {
+            let background = 60 + x as u8 * 3;
+            let ink = x % 10 == 5 && y > 5 && y < 15;
+            img.set_pixel(x, y, gray(if ink { background - 40 } else { background }));
+        }
+    }
+
+    img
+}
+
+fn check_adaptive(method: AdaptiveMethod) {
+    let mut img = uneven_lighting();
+    img.apply_filter(AdaptiveThreshold::new(method, 7, 5.0));
+
+    // Ink is found in both the dark and the bright part, background stays white
+    for x in [5, 25, 55] {
+        assert_eq!(img.get_pixel(x, 10), Some(&Colors::BLACK), "{:?} {}", method, x);
+    }
+
+    for x in [2, 20, 50] {
+        assert_eq!(img.get_pixel(x, 10), Some(&Colors::WHITE), "{:?} {}", method, x);
+        assert_eq!(img.get_pixel(x + 3, 2), Some(&Colors::WHITE), "{:?} {}", method, x);
+    }
+}
+
+#[test]
+fn test_adaptive_handles_uneven_lighting() {
+    check_adaptive(AdaptiveMethod::Mean);
+}
+
+#[test]
+fn test_adaptive_gaussian() {
+    check_adaptive(AdaptiveMethod::Gaussian);
+}
+
+#[test]
+fn test_adaptive_niblack() {
+    check_adaptive(AdaptiveMethod::Niblack { k: -0.2 });
+}
+
+#[test]
+fn test_adaptive_sauvola() {
+    // Low contrast ink needs a small k, the usual 0.5 is meant for dark text on paper
+    check_adaptive(AdaptiveMethod::Sauvola { k: 0.1, r: 128.0 });
+
+    // A global cutoff can not handle the same image
+    let mut img = uneven_lighting();
+    img.apply_filter(Threshold::otsu());
+    assert_ne!(img.get_pixel(5, 10), img.get_pixel(55, 10));
+}
+
+#[test]
+fn test_triangle_threshold() {
+    // Dominant dark peak at 50 with a long, sparse tail of bright values
+    let mut img = Image::new(64, 64);
+    for y in 0..64 {
+        for x in 0..64 {
+            let i = y * 64 + x;
+            let value = if i < 3000 { 50 - (i % 9) as u8 + (i % 7) as u8 } else { 100 + (i % 120) as u8 };
+            img.set_pixel(x, y, gray(value));
+        }
+    }
+
+    let cutoff = Histogram::from_image(&img).triangle_threshold(Channel::Luminance).unwrap();
+    assert!((57..100).contains(&cutoff), "{}", cutoff);
+
+    img.apply_filter(Threshold::triangle());
+    assert_eq!(img.get_pixel(0, 0), Some(&Colors::BLACK));
+    assert_eq!(img.get_pixel(63, 63), Some(&Colors::WHITE));
+
+    // A single value is its own cutoff
+    let flat = Image::new(4, 4);
+    assert_eq!(Histogram::from_image(&flat).triangle_threshold(Channel::Luminance), Some(0));
+    assert_eq!(Histogram::from_image(&Image::new(0, 0)).triangle_threshold(Channel::Luminance), None);
+}
\ No newline at end of file
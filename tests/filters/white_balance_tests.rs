@@ -0,0 +1,79 @@
+use rusty_imager::Image;
+use rusty_imager::filters::WhiteBalance;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::Pixel;
+
+fn color_cast_image() -> Image {
+    let mut img = Image::new(8, 8);
+    for x in 0..8 {
+        for y in 0..8 {
+            let value = 40 + (x * 8 + y) as u8 * 2;
+            img.set_pixel(x, y, Pixel { r: value, g: (value as f32 * 0.9) as u8, b: (value as f32 * 0.6) as u8 });
+        }
+    }
+
+    img
+}
+
+fn cast(pixel: &Pixel) -> i32 {
+    (pixel.r as i32 - pixel.b as i32).abs()
+}
+
+#[test]
+fn test_gray_world_removes_cast() {
+    let mut img = color_cast_image();
+    let before = cast(img.get_pixel(4, 4).unwrap());
+
+    img.apply_filter(WhiteBalance::gray_world());
+
+    assert!(cast(img.get_pixel(4, 4).unwrap()) < before / 4);
+}
+
+#[test]
+fn test_neutral_reference_becomes_gray() {
+    let mut img = color_cast_image();
+    let reference = img.get_pixel(7, 7).unwrap().clone();
+
+    img.apply_filter(WhiteBalance::neutral(reference));
+
+    let pixel = img.get_pixel(7, 7).unwrap();
+    assert!(cast(pixel) <= 1);
+    assert!((pixel.g as i32 - pixel.r as i32).abs() <= 1);
+}
+
+#[test]
+fn test_tungsten_temperature_cools_image() {
+    let mut img = Image::new(1, 1);
+    img.set_pixel(0, 0, Pixel { r: 128, g: 128, b: 128 });
+
+    img.apply_filter(WhiteBalance::temperature(3200.0, 0.0));
+
+    let pixel = img.get_pixel(0, 0).unwrap();
+    assert!(pixel.b > pixel.r);
+}
+
+#[test]
+fn test_white_patch_maps_to_white() {
+    // Bright patch lit by the same warm light and a single specular highlight
+    let patched = || {
+        let mut img = color_cast_image();
+        for x in 5..8 {
+            for y in 6..8 {
+                img.set_pixel(x, y, Pixel { r: 240, g: 216, b: 144 });
+            }
+        }
+        img.set_pixel(0, 0, Pixel { r: 255, g: 255, b: 255 });
+
+        img
+    };
+
+    let mut img = patched();
+    img.apply_filter(WhiteBalance::white_patch(100.0));
+    assert_eq!(img.get_pixel(6, 7), Some(&Pixel { r: 240, g: 216, b: 144 }));
+
+    // The highlight is ignored, the patch becomes white and the rest loses the cast
+    let mut img = patched();
+    img.apply_filter(WhiteBalance::white_patch(95.0));
+    assert_eq!(img.get_pixel(6, 7), Some(&Pixel { r: 255, g: 255, b: 255 }));
+    assert!(cast(img.get_pixel(4, 4).unwrap()) <= 2);
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::generators::{checkerboard, Gradient};
+use rusty_imager::models::{Colors, Pixel, Point};
+
+use crate::common::gray;
+
+#[test]
+fn test_color_stops() {
+    let gradient = Gradient::linear(
+        Point::new(0.0, 0.0),
+        Point::new(10.0, 0.0),
+        &[(1.0, Colors::BLUE), (0.0, Colors::RED), (0.5, Colors::WHITE)]
+    );
+
+    assert_eq!(gradient.color_at(-1.0), Colors::RED);
+    assert_eq!(gradient.color_at(0.0), Colors::RED);
+    assert_eq!(gradient.color_at(0.25), Pixel { r: 255, g: 128, b: 128 });
+    assert_eq!(gradient.color_at(0.5), Colors::WHITE);
+    assert_eq!(gradient.color_at(0.75), Pixel { r: 128, g: 128, b: 255 });
+    assert_eq!(gradient.color_at(2.0), Colors::BLUE);
+
+    let single = Gradient::linear(Point::new(0.0, 0.0), Point::new(1.0, 0.0), &[(0.3, Colors::GOLD)]);
+    assert_eq!(single.color_at(0.9), Colors::GOLD);
+}
+
+#[test]
+fn test_linear_gradient() {
+    let gradient = Gradient::linear(Point::new(0.0, 0.0), Point::new(0.0, 4.0), &[(0.0, Colors::BLACK), (1.0, gray(200))]);
+    let img = gradient.render(3, 6);
+
+    // Constant along the rows, extended past the end point
+    for y in 0..6 {
+        let expected = gray((y.min(4) * 50) as u8);
+        for x in 0..3 {
+            assert_eq!(img.get_pixel(x, y), Some(&expected), "{} {}", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_radial_and_conic_gradients() {
+    let radial = Gradient::radial(Point::new(5.0, 5.0), 5.0, &[(0.0, Colors::WHITE), (1.0, Colors::BLACK)]).render(11, 11);
+    assert_eq!(radial.get_pixel(5, 5), Some(&Colors::WHITE));
+    assert_eq!(radial.get_pixel(5, 0), Some(&Colors::BLACK));
+    assert_eq!(radial.get_pixel(0, 0), Some(&Colors::BLACK));
+    assert_eq!(radial.get_pixel(8, 1), radial.get_pixel(1, 8));
+
+    let conic = Gradient::conic(Point::new(5.0, 5.0), 0.0, &[(0.0, Colors::BLACK), (1.0, gray(200))]);
+    assert_eq!(conic.offset_at(Point::new(9.0, 5.0)), 0.0);
+    assert!((conic.offset_at(Point::new(5.0, 9.0)) - 0.25).abs() < 1e-6);
+    assert!((conic.offset_at(Point::new(1.0, 5.0)) - 0.5).abs() < 1e-6);
+    assert!((conic.offset_at(Point::new(5.0, 1.0)) - 0.75).abs() < 1e-6);
+
+    let img = conic.render(11, 11);
+    assert_eq!(img.get_pixel(1, 5), Some(&gray(100)));
+}
+
+#[test]
+fn test_checkerboard() {
+    let board = checkerboard(7, 5, 2, Colors::WHITE, Colors::BLACK);
+
+    for y in 0..5 {
+        for x in 0..7 {
+            let expected = if (x / 2 + y / 2) % 2 == 0 { Colors::WHITE } else { Colors::BLACK };
+            assert_eq!(board.get_pixel(x, y), Some(&expected), "{} {}", x, y);
+        }
+    }
+}
\ No newline at end of file
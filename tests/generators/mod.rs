@@ -0,0 +1,2 @@
+mod gradient_tests;
+mod noise_tests;
\ No newline at end of file
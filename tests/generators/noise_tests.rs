@@ -0,0 +1,59 @@
+use rusty_imager::analysis::Statistics;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::generators::Noise;
+use rusty_imager::models::Channel;
+
+fn check_noise(noise: &Noise) {
+    let mut min = f32::MAX;
+    let mut max = f32::MIN;
+
+    for i in 0..4000 {
+        let (x, y) = (i as f32 * 0.137, (i % 61) as f32 * 0.291);
+        let value = noise.sample(x, y);
+        min = min.min(value);
+        max = max.max(value);
+
+        // Neighbouring samples stay close
+        assert!((noise.sample(x + 0.01, y) - value).abs() < 0.1);
+    }
+
+    assert!(min > -1.05 && max < 1.05, "{} {}", min, max);
+    assert!(min < -0.3 && max > 0.3, "{} {}", min, max);
+}
+
+#[test]
+fn test_perlin_noise() {
+    let noise = Noise::perlin(3);
+    check_noise(&noise);
+
+    // Zero at the grid points
+    assert_eq!(noise.sample(4.0, -7.0), 0.0);
+}
+
+#[test]
+fn test_simplex_noise() {
+    check_noise(&Noise::simplex(3));
+}
+
+#[test]
+fn test_noise_is_deterministic() {
+    let a = Noise::perlin(9);
+    let b = Noise::perlin(9);
+    let c = Noise::perlin(10);
+
+    assert_eq!(a.fractal(1.3, 2.7, 4, 0.5), b.fractal(1.3, 2.7, 4, 0.5));
+    assert_ne!(a.sample(1.3, 2.7), c.sample(1.3, 2.7));
+}
+
+#[test]
+fn test_render_noise() {
+    let img = Noise::simplex(5).render(64, 64, 16.0, 3);
+    assert_eq!((img.get_width(), img.get_height()), (64, 64));
+
+    let stats = Statistics::from_image(&img, Channel::Red).unwrap();
+    assert!((stats.mean - 127.5).abs() < 30.0);
+    assert!(stats.std_dev > 10.0);
+
+    let pixel = img.get_pixel(10, 10).unwrap();
+    assert!(pixel.r == pixel.g && pixel.g == pixel.b);
+}
\ No newline at end of file
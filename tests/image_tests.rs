@@ -0,0 +1,42 @@
+use rusty_imager::Image;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Pixel};
+
+#[test]
+fn test_resize_down_averages() {
+    let mut img = Image::new(4, 2);
+    for y in 0..2 {
+        img.set_pixel(0, y, Colors::WHITE);
+        img.set_pixel(2, y, Pixel { r: 100, g: 50, b: 0 });
+        img.set_pixel(3, y, Pixel { r: 100, g: 50, b: 0 });
+    }
+
+    let small = img.resize(2, 1);
+
+    assert_eq!(small.get_width(), 2);
+    assert_eq!(small.get_height(), 1);
+    assert_eq!(small.get_pixel(0, 0), Some(&Pixel { r: 128, g: 128, b: 128 }));
+    assert_eq!(small.get_pixel(1, 0), Some(&Pixel { r: 100, g: 50, b: 0 }));
+}
+
+#[test]
+fn test_resize_up_repeats() {
+    let mut img = Image::new(2, 2);
+    img.set_pixel(1, 1, Colors::RED);
+
+    let big = img.resize(6, 4);
+
+    assert_eq!(big.get_pixel(5, 3), Some(&Colors::RED));
+    assert_eq!(big.get_pixel(3, 2), Some(&Colors::RED));
+    assert_eq!(big.get_pixel(2, 2), Some(&Colors::BLACK));
+    assert_eq!(big.get_pixel(0, 0), Some(&Colors::BLACK));
+}
+
+#[test]
+fn test_empty_image() {
+    let mut img = Image::new(0, 0);
+
+    assert_eq!(img.get_pixel(0, 0), None);
+    assert_eq!(img.set_pixel(0, 0, Colors::WHITE), None);
+    assert_eq!(Image::new(3, 0).get_pixel(1, 0), None);
+}
\ No newline at end of file
@@ -1,4 +1,11 @@
+mod common;
 mod parsing;
+mod analysis;
+mod filters;
+mod quantization;
+mod drawing;
+mod generators;
 mod pixel_tests;
+mod image_tests;
 
 // TODO: Add more test for img and filters
\ No newline at end of file
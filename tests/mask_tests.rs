@@ -0,0 +1,80 @@
+use rusty_imager::{Image, Mask};
+use rusty_imager::drawing::{fill_rect, magic_wand, ColorTolerance};
+use rusty_imager::filters::ColorInversion;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Connectivity, Rect};
+
+mod common;
+
+use common::gray;
+
+#[test]
+fn test_mask_sources() {
+    let mut img = Image::new(4, 1);
+    for x in 0..4 {
+        img.set_pixel(x, 0, gray(x as u8 * 80));
+    }
+
+    let from_image = Mask::from_image(&img);
+    assert_eq!(from_image.get(0, 0), Some(0.0));
+    assert_eq!(from_image.get(3, 0), Some(240.0 / 255.0));
+    assert_eq!(from_image.get(4, 0), None);
+
+    let shadows = Mask::from_threshold(&img, 0, 100);
+    assert_eq!(shadows.values(), &[1.0, 1.0, 0.0, 0.0]);
+    assert_eq!(shadows.invert().values(), &[0.0, 0.0, 1.0, 1.0]);
+
+    let shapes = Mask::from_shapes(4, 4, |canvas| fill_rect(canvas, Rect::new(1, 1, 2, 2), Colors::WHITE));
+    assert_eq!(shapes.values().iter().filter(|&&v| v == 1.0).count(), 4);
+    assert_eq!(shapes.get(1, 2), Some(1.0));
+
+    let selection = magic_wand(&img, 0, 0, ColorTolerance::Rgb(0.0), Connectivity::Four);
+    assert_eq!(Mask::from_selection(&selection, 4, 1).values(), &[1.0, 0.0, 0.0, 0.0]);
+
+    assert_eq!(Mask::from_values(vec![2.0, -1.0], 2, 1).values(), &[1.0, 0.0]);
+}
+
+#[test]
+fn test_feather() {
+    let mask = Mask::from_shapes(20, 1, |canvas| fill_rect(canvas, Rect::new(10, 0, 10, 1), Colors::WHITE));
+    let feathered = mask.feather(2.0);
+
+    // The edge becomes a monotonic ramp, far pixels keep their weight
+    let values = feathered.values();
+    assert!(values.windows(2).all(|w| w[0] <= w[1] + 1e-6));
+    assert!(values[0] < 1e-3 && values[19] > 1.0 - 1e-3);
+    assert!((values[9] + values[10] - 1.0).abs() < 1e-3);
+    assert!(values[9] > 0.2 && values[10] < 0.8);
+
+    assert_eq!(mask.feather(0.0), mask);
+}
+
+#[test]
+fn test_apply_filter_masked() {
+    let mut img = Image::new(3, 1);
+    for x in 0..3 {
+        img.set_pixel(x, 0, gray(200));
+    }
+
+    let mask = Mask::from_values(vec![0.0, 0.5, 1.0], 3, 1);
+    img.apply_filter_masked(ColorInversion::new(), &mask);
+
+    assert_eq!(img.get_pixel(0, 0), Some(&gray(200)));
+    assert_eq!(img.get_pixel(1, 0), Some(&gray(128)));
+    assert_eq!(img.get_pixel(2, 0), Some(&gray(55)));
+
+    let full = Mask::filled(3, 1, 1.0);
+    let mut expected = Image::new(3, 1);
+    expected.apply_filter(ColorInversion::new());
+
+    let mut masked = Image::new(3, 1);
+    masked.apply_filter_masked(ColorInversion::new(), &full);
+    assert_eq!(masked.to_bytes(), expected.to_bytes());
+}
+
+#[test]
+#[should_panic]
+fn test_apply_filter_masked_size_mismatch() {
+    let mut img = Image::new(3, 1);
+    img.apply_filter_masked(ColorInversion::new(), &Mask::new(2, 2));
+}
\ No newline at end of file
@@ -25,4 +25,37 @@ fn test_to_hex() {
     let white = Colors::WHITE;
 
     assert_eq!(white.to_hex(), "#ffffff");
+}
+
+#[test]
+fn test_from_short_and_alpha_hex() {
+    assert_eq!(Pixel::from_hex("#f80"), Some(Pixel { r: 255, g: 136, b: 0 }));
+    assert_eq!(Pixel::from_hex("#0a0b0c80"), Some(Pixel { r: 10, g: 11, b: 12 }));
+    assert!(Pixel::from_hex("#12345").is_none());
+}
+
+#[test]
+fn test_hex_round_trip() {
+    let pixel = Pixel { r: 1, g: 2, b: 3 };
+
+    assert_eq!(pixel.to_hex(), "#010203");
+    assert_eq!(Pixel::from_hex(&pixel.to_hex()), Some(pixel));
+}
+
+#[test]
+fn test_from_css() {
+    assert_eq!(Pixel::from_css("rgba(100, 149, 237, 0.5)"), Some(Colors::CORN_FLOWER_BLUE));
+    assert_eq!(Pixel::from_css("rgb(100% 0% 0%)"), Some(Colors::RED));
+    assert_eq!(Pixel::from_css("hsl(0, 0%, 100%)"), Some(Colors::WHITE));
+    assert_eq!(Pixel::from_css("hsla(240deg, 100%, 50%, 1)"), Some(Colors::BLUE));
+    assert_eq!(Pixel::from_css("  DarkGrey "), Some(Colors::DARK_GRAY));
+    assert!(Pixel::from_css("rgb(1, 2)").is_none());
+    assert!(Pixel::from_css("notacolor").is_none());
+}
+
+#[test]
+fn test_color_names() {
+    assert_eq!(Colors::by_name("cornflowerblue"), Some(Colors::CORN_FLOWER_BLUE));
+    assert_eq!(Colors::name_of(&Colors::TEAL), Some("teal"));
+    assert_eq!(Colors::nearest(&Pixel { r: 101, g: 148, b: 240 }).0, "cornflowerblue");
 }
\ No newline at end of file
@@ -0,0 +1 @@
+mod quantization_tests;
\ No newline at end of file
@@ -0,0 +1,77 @@
+use rusty_imager::Image;
+use rusty_imager::formats::ImageFormat;
+use rusty_imager::models::{Colors, Pixel};
+use rusty_imager::quantization::{IndexedImage, KMeans, MedianCut, Octree, Quantizer, Swatch};
+
+/// Left half red, right top quarter blue, right bottom quarter white, with slight variations
+fn regions_image() -> Image {
+    let mut img = Image::new(16, 16);
+
+    for x in 0..16 {
+        for y in 0..16 {
+            let noise = ((x * 7 + y * 3) % 5) as u8;
+            let base = if x < 8 {
+                Colors::RED
+            } else if y < 8 {
+                Colors::BLUE
+            } else {
+                Colors::WHITE
+            };
+
+            let px = Pixel {
+                r: base.r.saturating_sub(noise),
+                g: base.g.saturating_add(noise),
+                b: base.b.saturating_sub(noise)
+            };
+            img.set_pixel(x, y, px);
+        }
+    }
+
+    img
+}
+
+fn check_quantizer(quantizer: &impl Quantizer) {
+    let img = regions_image();
+    let indexed = IndexedImage::from_image(&img, quantizer);
+
+    assert!(indexed.palette().len() <= 3);
+    assert_eq!(indexed.indices().len(), 256);
+
+    let reduced = indexed.to_image();
+    for (x, y) in [(2, 2), (12, 2), (12, 12)] {
+        let original = img.get_pixel(x, y).unwrap();
+        assert!(reduced.get_pixel(x, y).unwrap().distance_squared(original) < 100);
+    }
+}
+
+#[test]
+fn test_median_cut() {
+    check_quantizer(&MedianCut::new(3));
+}
+
+#[test]
+fn test_octree() {
+    check_quantizer(&Octree::new(3));
+}
+
+#[test]
+fn test_kmeans() {
+    check_quantizer(&KMeans::new(3, 10));
+}
+
+#[test]
+fn test_dominant_colors() {
+    let swatches = Swatch::dominant(&regions_image(), 3);
+
+    assert_eq!(swatches.len(), 3);
+    assert_eq!(swatches[0].name, "red");
+    assert!((swatches[0].proportion - 0.5).abs() < 1e-3);
+
+    let total: f32 = swatches.iter().map(|s| s.proportion).sum();
+    assert!((total - 1.0).abs() < 1e-3);
+
+    assert!(Swatch::dominant(&regions_image(), 0).is_empty());
+
+    // Capped at 256 colors, only the 15 distinct ones are found
+    assert_eq!(Swatch::dominant(&regions_image(), 1000).len(), 15);
+}
\ No newline at end of file